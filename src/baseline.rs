@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::structs::{ScanResults, Vulnerability};
+
+/// The persisted form of a baseline: just the set of stable `Vulnerability::hash` fingerprints
+/// seen in a prior scan.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BaselineFile {
+    fingerprints: HashSet<String>,
+}
+
+/// A scan's findings split against a baseline: `new` findings whose fingerprint wasn't in the
+/// baseline, `existing` findings that were already there, and `fixed` baseline fingerprints that
+/// no longer appear in the current scan.
+pub struct BaselineDiff<'a> {
+    pub new: Vec<&'a Vulnerability>,
+    pub existing: Vec<&'a Vulnerability>,
+    pub fixed: Vec<String>,
+}
+
+/// Load a previously-written baseline file. A missing file is treated as an empty baseline (every
+/// finding comes back as `new`) rather than an error, so the first scan in a repo doesn't fail.
+pub fn load_baseline(path: &Path) -> Result<HashSet<String>> {
+    if !path.exists() {
+        return Ok(HashSet::new());
+    }
+    let file: BaselineFile = serde_json::from_str(&fs::read_to_string(path)?)?;
+    Ok(file.fingerprints)
+}
+
+/// Persist the current scan's fingerprints as the new baseline.
+pub fn write_baseline(path: &Path, results: &ScanResults) -> Result<()> {
+    let fingerprints = results.vulnerabilities.iter().map(|v| v.hash.clone()).collect();
+    fs::write(path, serde_json::to_string_pretty(&BaselineFile { fingerprints })?)?;
+    Ok(())
+}
+
+/// Diff `results` against a previously-loaded `baseline`.
+pub fn diff_against_baseline<'a>(
+    results: &'a ScanResults,
+    baseline: &HashSet<String>,
+) -> BaselineDiff<'a> {
+    let mut new = Vec::new();
+    let mut existing = Vec::new();
+    let mut seen = HashSet::new();
+
+    for vulnerability in &results.vulnerabilities {
+        seen.insert(vulnerability.hash.clone());
+        if baseline.contains(&vulnerability.hash) {
+            existing.push(vulnerability);
+        } else {
+            new.push(vulnerability);
+        }
+    }
+    let fixed = baseline.difference(&seen).cloned().collect();
+
+    BaselineDiff { new, existing, fixed }
+}
@@ -0,0 +1,152 @@
+//! A reproducible benchmark harness: runs the scan pipeline over a set of versioned fixture
+//! repositories described by small workload manifests, and reports per-phase timings plus
+//! throughput, so scanner changes can be diffed across commits to catch performance or
+//! detection regressions instead of relying on ad-hoc, eyeballed runs.
+//!
+//! A workload manifest is a JSON file naming a target directory (relative to the manifest
+//! itself), a human-readable label, and the finding counts a healthy scanner is expected to
+//! produce against it:
+//!
+//! ```json
+//! { "label": "medium-django-app", "target": "fixtures/django-app", "expected_occurrences": 42 }
+//! ```
+//!
+//! Each run walks the target with [`get_files_in_dir`], scans every Python/TypeScript file
+//! through [`BaseScanner::scan_file_timed`] to split out parse time from AST-walk time (AST
+//! walking is also where occurrences and intra-file vulnerabilities are emitted, as a side
+//! effect of visiting), then runs the cross-file [`TaintEngine`] resolution pass that emits
+//! vulnerabilities spanning multiple files — the three phases the scan pipeline actually
+//! exposes distinct timings for.
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tree_sitter_python::language as language_python;
+use tree_sitter_typescript::language_typescript;
+
+use crate::scanner::database::ScanDatabase;
+use crate::scanner::languages::base::{BaseScanner, FileScanTimings};
+use crate::scanner::languages::{PythonScanner, TypescriptScanner};
+use crate::scanner::taint::TaintEngine;
+use crate::scanner::{initialize_database, initialize_parser};
+use crate::structs::ScanConfig;
+use crate::utils::file::{get_file_line_count, get_files_in_dir};
+
+/// A labeled fixture workload and the finding counts a healthy scanner is expected to produce
+/// against it, loaded from a JSON manifest.
+#[derive(Debug, Deserialize)]
+pub struct Workload {
+    pub label: String,
+    /// Path to the fixture directory, relative to the manifest file.
+    pub target: PathBuf,
+    pub expected_occurrences: Option<usize>,
+    pub expected_vulnerabilities: Option<usize>,
+}
+
+/// Load a workload manifest from `path`, resolving `target` relative to the manifest's
+/// directory so manifests are relocatable along with their fixtures.
+pub fn load_workload(path: &Path) -> Result<Workload> {
+    let mut workload: Workload = serde_json::from_str(&std::fs::read_to_string(path)?)
+        .with_context(|| format!("Failed to parse workload manifest {}", path.display()))?;
+    if let Some(manifest_dir) = path.parent() {
+        workload.target = manifest_dir.join(&workload.target);
+    }
+    Ok(workload)
+}
+
+/// Per-phase timings and throughput for one workload run, plus whether the observed finding
+/// counts matched the manifest's expectations (`None` when the manifest didn't declare one).
+#[derive(Debug, Serialize)]
+pub struct WorkloadResult {
+    pub label: String,
+    pub file_count: usize,
+    pub line_count: usize,
+    pub parse_seconds: f64,
+    pub visit_seconds: f64,
+    pub taint_resolution_seconds: f64,
+    pub total_seconds: f64,
+    pub files_per_sec: f64,
+    pub lines_per_sec: f64,
+    pub occurrences_found: usize,
+    pub vulnerabilities_found: usize,
+    pub occurrences_matched_expected: Option<bool>,
+    pub vulnerabilities_matched_expected: Option<bool>,
+}
+
+/// Run every language scanner over `workload.target` and aggregate the per-phase timings.
+pub fn run_workload(workload: &Workload, config: &ScanConfig) -> Result<WorkloadResult> {
+    let database = initialize_database();
+    let mut py_parser = initialize_parser(language_python());
+    let mut ts_parser = initialize_parser(language_typescript());
+
+    let mut file_count = 0;
+    let mut line_count = 0;
+    let mut parse_total = std::time::Duration::ZERO;
+    let mut visit_total = std::time::Duration::ZERO;
+
+    let total_start = Instant::now();
+    for file in get_files_in_dir(&workload.target, None, None) {
+        let timings: Option<FileScanTimings> =
+            match file.extension().and_then(|ext| ext.to_str()) {
+                Some("py") => {
+                    Some(PythonScanner::scan_file_timed(&database, config, &mut py_parser, &file)?)
+                }
+                Some("js") | Some("jsx") | Some("ts") | Some("tsx") => Some(
+                    TypescriptScanner::scan_file_timed(&database, config, &mut ts_parser, &file)?,
+                ),
+                _ => None,
+            };
+        let Some(timings) = timings else { continue };
+
+        file_count += 1;
+        line_count += get_file_line_count(&file).unwrap_or(0);
+        parse_total += timings.parse;
+        visit_total += timings.visit;
+    }
+
+    let taint_start = Instant::now();
+    {
+        let collector = database.taint();
+        let engine = TaintEngine::new(&collector);
+        for path in engine.resolve() {
+            if let Some(vulnerability) = crate::scanner::build_taint_vulnerability(config, &path) {
+                database.put_vulnerability(&vulnerability)?;
+            }
+        }
+    }
+    let taint_resolution_seconds = taint_start.elapsed().as_secs_f64();
+    let total_seconds = total_start.elapsed().as_secs_f64();
+
+    let occurrences_found = database.get_data_element_occurrences()?.len();
+    let vulnerabilities_found = database.get_vulnerabilities()?.len();
+
+    Ok(WorkloadResult {
+        label: workload.label.clone(),
+        file_count,
+        line_count,
+        parse_seconds: parse_total.as_secs_f64(),
+        visit_seconds: visit_total.as_secs_f64(),
+        taint_resolution_seconds,
+        total_seconds,
+        files_per_sec: safe_rate(file_count, total_seconds),
+        lines_per_sec: safe_rate(line_count, total_seconds),
+        occurrences_found,
+        vulnerabilities_found,
+        occurrences_matched_expected: workload
+            .expected_occurrences
+            .map(|expected| expected == occurrences_found),
+        vulnerabilities_matched_expected: workload
+            .expected_vulnerabilities
+            .map(|expected| expected == vulnerabilities_found),
+    })
+}
+
+fn safe_rate(count: usize, seconds: f64) -> f64 {
+    if seconds > 0.0 {
+        count as f64 / seconds
+    } else {
+        0.0
+    }
+}
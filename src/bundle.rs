@@ -0,0 +1,253 @@
+use std::io::{self, Read, Write};
+
+use anyhow::{bail, Result};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::structs::ScanResults;
+
+const BUNDLE_FORMAT_VERSION: &str = "1";
+const TOOL_VERSION: &str = "1.0.0";
+
+/// A [`Write`] adapter that feeds every byte passed through it into a running SHA-256 digest
+/// before forwarding it to the inner writer.
+struct HashWriter<W> {
+    inner: W,
+    hasher: Sha256,
+}
+
+impl<W: Write> HashWriter<W> {
+    fn new(inner: W) -> HashWriter<W> {
+        HashWriter { inner, hasher: Sha256::new() }
+    }
+
+    fn finalize_hex(self) -> String {
+        to_hex(&self.hasher.finalize())
+    }
+}
+
+impl<W: Write> Write for HashWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Result<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        bail!("Odd-length hex string: {}", hex);
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|e| anyhow::anyhow!(e)))
+        .collect()
+}
+
+/// A detached ed25519 signature over a bundle's content digest, plus the id of the key that
+/// produced it so a verifier knows which public key to check against.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BundleSignature {
+    pub key_id: String,
+    pub signature: String,
+}
+
+/// Provenance metadata for a scan-result bundle: what was scanned, with what tool, and a
+/// content hash an auditor can recompute offline to prove the payload wasn't tampered with.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BundleHeader {
+    pub format_version: String,
+    pub tool_version: String,
+    pub repository_url: String,
+    pub branch: String,
+    pub commit: String,
+    pub payload_sha256: String,
+    #[serde(default)]
+    pub signature: Option<BundleSignature>,
+}
+
+/// A content-addressed, optionally signed scan-result artifact: a header recording the repo,
+/// commit, and tool version a `ScanResults` payload came from, plus the payload itself.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Bundle {
+    pub header: BundleHeader,
+    pub payload: serde_json::Value,
+}
+
+/// Serialize `results` deterministically (`serde_json::Value`'s maps are key-sorted), stream
+/// the bytes through a SHA-256 [`HashWriter`] to produce the content digest, and write the
+/// resulting [`Bundle`] to `writer`. When `signing_key` is given, the digest is also signed and
+/// the signature is recorded alongside `key_id` so a verifier can check it against the matching
+/// public key.
+pub fn write_bundle<W: Write>(
+    writer: W,
+    results: &ScanResults,
+    signing_key: Option<(&SigningKey, &str)>,
+) -> Result<()> {
+    let payload = serde_json::to_value(results)?;
+
+    let mut hash_writer = HashWriter::new(io::sink());
+    hash_writer.write_all(&serde_json::to_vec(&payload)?)?;
+    let payload_sha256 = hash_writer.finalize_hex();
+
+    let signature = signing_key.map(|(key, key_id)| BundleSignature {
+        key_id: key_id.to_string(),
+        signature: to_hex(&key.sign(payload_sha256.as_bytes()).to_bytes()),
+    });
+
+    let bundle = Bundle {
+        header: BundleHeader {
+            format_version: BUNDLE_FORMAT_VERSION.to_string(),
+            tool_version: TOOL_VERSION.to_string(),
+            repository_url: results.repository.base_url.clone(),
+            branch: results.repository.branch.clone(),
+            commit: results.repository.commit.clone(),
+            payload_sha256,
+            signature,
+        },
+        payload,
+    };
+    serde_json::to_writer(writer, &bundle)?;
+    Ok(())
+}
+
+pub fn read_bundle<R: Read>(reader: R) -> Result<Bundle> {
+    Ok(serde_json::from_reader(reader)?)
+}
+
+/// Recompute the payload digest and, when `verifying_key` is given, check the recorded
+/// signature against it. Fails if the payload was tampered with (digest mismatch), if a
+/// signature was requested but the bundle has none, or if the signature doesn't verify.
+pub fn verify_bundle(bundle: &Bundle, verifying_key: Option<&VerifyingKey>) -> Result<()> {
+    let mut hash_writer = HashWriter::new(io::sink());
+    hash_writer.write_all(&serde_json::to_vec(&bundle.payload)?)?;
+    let recomputed_sha256 = hash_writer.finalize_hex();
+
+    if recomputed_sha256 != bundle.header.payload_sha256 {
+        bail!(
+            "Bundle payload does not match its recorded digest (expected {}, got {}); the \
+             bundle may have been tampered with or corrupted",
+            bundle.header.payload_sha256,
+            recomputed_sha256
+        );
+    }
+
+    if let Some(verifying_key) = verifying_key {
+        let signature = bundle
+            .header
+            .signature
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Bundle has no signature to verify"))?;
+        let signature_bytes: [u8; 64] =
+            from_hex(&signature.signature)?.try_into().map_err(|_| {
+                anyhow::anyhow!("Malformed ed25519 signature in bundle")
+            })?;
+        verifying_key
+            .verify(bundle.header.payload_sha256.as_bytes(), &Signature::from_bytes(&signature_bytes))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::{Repository, ScanConfig};
+    use std::collections::{HashMap, HashSet};
+    use std::path::PathBuf;
+
+    fn test_results(config: &ScanConfig) -> ScanResults {
+        ScanResults::new(config, vec![], vec![])
+    }
+
+    fn test_config() -> ScanConfig {
+        ScanConfig {
+            is_debug: false,
+            is_paid_features_enabled: false,
+            repository: Repository {
+                path: PathBuf::from("/repo"),
+                base_url: "https://github.com/org/repo".to_string(),
+                name: "org/repo".to_string(),
+                branch: "main".to_string(),
+                commit: "abc123".to_string(),
+                git_provider: None,
+                per_lang_file_stats: HashMap::new(),
+                total_file_stats: Default::default(),
+            },
+            data_elements: HashMap::new(),
+            data_sinks: HashMap::new(),
+            sanitizers: vec![],
+            output_filename: None,
+            output_format: crate::enums::OutputFormat::Console,
+            skip_data_elements: HashSet::new(),
+            skip_data_sinks: HashSet::new(),
+            skip_occurrences: HashSet::new(),
+            skip_vulnerabilities: HashSet::new(),
+            include_severity: Vec::new(),
+            fail_severity_threshold: None,
+            graphql: Default::default(),
+            targets: Vec::new(),
+            diff_baseline: None,
+            empty_diff_mode: Default::default(),
+            unmatched_path_mode: Default::default(),
+            markdown_syntax_highlighting: false,
+            data_element_matcher: crate::scanner::matcher::DataElementMatcher::build(
+                &HashMap::new(),
+            ),
+            include_globs: None,
+            exclude_globs: None,
+        }
+    }
+
+    #[test]
+    fn round_trips_and_verifies_a_signed_bundle() {
+        let config = test_config();
+        let results = test_results(&config);
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let verifying_key = signing_key.verifying_key();
+
+        let mut bytes = Vec::new();
+        write_bundle(&mut bytes, &results, Some((&signing_key, "test-key-1"))).unwrap();
+
+        let bundle = read_bundle(bytes.as_slice()).unwrap();
+        assert_eq!(bundle.header.commit, "abc123");
+        assert_eq!(bundle.header.signature.as_ref().unwrap().key_id, "test-key-1");
+        verify_bundle(&bundle, Some(&verifying_key)).unwrap();
+    }
+
+    #[test]
+    fn rejects_a_corrupted_payload() {
+        let config = test_config();
+        let results = test_results(&config);
+
+        let mut bytes = Vec::new();
+        write_bundle(&mut bytes, &results, None).unwrap();
+
+        let mut bundle = read_bundle(bytes.as_slice()).unwrap();
+        bundle.payload["repository"]["commit"] = serde_json::json!("tampered");
+
+        assert!(verify_bundle(&bundle, None).is_err());
+    }
+
+    #[test]
+    fn rejects_a_bad_signature() {
+        let config = test_config();
+        let results = test_results(&config);
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let other_verifying_key = SigningKey::from_bytes(&[9u8; 32]).verifying_key();
+
+        let mut bytes = Vec::new();
+        write_bundle(&mut bytes, &results, Some((&signing_key, "test-key-1"))).unwrap();
+        let bundle = read_bundle(bytes.as_slice()).unwrap();
+
+        assert!(verify_bundle(&bundle, Some(&other_verifying_key)).is_err());
+    }
+}
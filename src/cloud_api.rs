@@ -1,18 +1,34 @@
 use std::collections::HashMap;
+use std::future::Future;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use anyhow::Result;
-use reqwest::blocking::{Client as HttpClient, Request as HttpRequest};
-use reqwest::header::{HeaderMap, ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use reqwest::header::{HeaderMap, ACCEPT, AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER};
+use reqwest::{Client as HttpClient, Request as HttpRequest};
 use serde::de::DeserializeOwned;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::enums::{HoundDogEnv, Language};
 use crate::sentry_err;
 use crate::structs::{DataElement, DataSink, Sanitizer, ScanResults, User};
+use crate::utils::pkce;
+
+/// How many times `send_request` retries a transient failure (`5xx`, connection error, or
+/// `429`) before giving up.
+const MAX_RETRY_ATTEMPTS: u32 = 5;
+/// Base delay for the exponential backoff applied between retries.
+const BASE_RETRY_DELAY: Duration = Duration::from_millis(500);
+/// Registered OAuth client id for this CLI.
+const OAUTH_CLIENT_ID: &str = "hounddog-cli";
 
 #[derive(Deserialize)]
 pub struct PaginatedResponse<T> {
     pub items: Vec<T>,
+    #[serde(default)]
+    pub next_cursor: Option<String>,
 }
 
 pub struct HoundDogCloudApi {
@@ -21,7 +37,15 @@ pub struct HoundDogCloudApi {
 }
 
 impl HoundDogCloudApi {
-    pub fn new(env: &HoundDogEnv, api_key: &str) -> Result<Self> {
+    /// Build a client authenticated with `api_key`, falling back to a token saved by a prior
+    /// [`login`](HoundDogCloudApi::login) when `api_key` is empty (no `HOUNDDOG_API_KEY` set).
+    pub fn new(env: &HoundDogEnv, api_key: &str, home_dir_path: &Path) -> Result<Self> {
+        let api_key = if api_key.is_empty() {
+            load_access_token(home_dir_path).unwrap_or_default()
+        } else {
+            api_key.to_string()
+        };
+
         Ok(Self {
             http: HttpClient::builder()
                 .default_headers({
@@ -36,55 +60,134 @@ impl HoundDogCloudApi {
                 .build()
                 .unwrap(),
 
-            base_url: match env {
-                HoundDogEnv::Dev => "http://localhost:8000".to_string(),
-                HoundDogEnv::Staging => "https://api.staging.hounddog.ai".to_string(),
-                HoundDogEnv::Prod => "https://api.hounddog.ai".to_string(),
-            },
+            base_url: base_url_for(env),
         })
     }
 
-    fn send_request<T: DeserializeOwned>(&self, request: HttpRequest) -> Result<T> {
-        match self.http.execute(request) {
-            Ok(response) => {
-                if response.status().is_success() {
-                    let response_text = response.text()?;
-                    let response_json: T = serde_json::from_str(&response_text)?;
-                    Ok(response_json)
-                } else if response.status() == reqwest::StatusCode::UNAUTHORIZED {
-                    Err(sentry_err!("Unauthorized. Please check your HOUNDDOG_API_KEY."))
-                } else {
-                    Err(sentry_err!(
-                        "HoundDog Cloud returned an error: {}",
-                        response.text().unwrap_or_default()
-                    ))
+    /// Execute `request`, retrying transient failures with exponential backoff plus jitter: a
+    /// `429` honors the server's `Retry-After` header, a `5xx` or connection error backs off and
+    /// retries up to [`MAX_RETRY_ATTEMPTS`] times. A `401` is never retried and surfaces
+    /// immediately, since no amount of retrying fixes a bad API key.
+    async fn send_request<T: DeserializeOwned>(&self, request: HttpRequest) -> Result<T> {
+        let mut attempt = 0;
+        loop {
+            let attempt_request = request
+                .try_clone()
+                .ok_or_else(|| sentry_err!("Request body does not support retries"))?;
+            match self.http.execute(attempt_request).await {
+                Ok(response) => {
+                    let status = response.status();
+                    if status.is_success() {
+                        let response_text = response.text().await?;
+                        return Ok(serde_json::from_str(&response_text)?);
+                    }
+                    if status == reqwest::StatusCode::UNAUTHORIZED {
+                        return Err(sentry_err!(
+                            "Unauthorized. Please check your HOUNDDOG_API_KEY."
+                        ));
+                    }
+                    let is_retryable = status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                        || status.is_server_error();
+                    if attempt >= MAX_RETRY_ATTEMPTS || !is_retryable {
+                        return Err(sentry_err!(
+                            "HoundDog Cloud returned an error: {}",
+                            response.text().await.unwrap_or_default()
+                        ));
+                    }
+                    attempt += 1;
+                    let delay = retry_after(&response).unwrap_or_else(|| backoff_delay(attempt));
+                    tokio::time::sleep(delay).await;
                 }
+                Err(_) if attempt < MAX_RETRY_ATTEMPTS => {
+                    attempt += 1;
+                    tokio::time::sleep(backoff_delay(attempt)).await;
+                }
+                Err(e) => return Err(sentry_err!("Failed to connect to HoundDog Cloud: {e}")),
+            }
+        }
+    }
+
+    /// Fetch every page of a cursor-paginated endpoint at `path`, following `next_cursor` until
+    /// the server returns `None`, so large catalogs aren't silently truncated to page one.
+    async fn get_paginated<T: DeserializeOwned>(&self, path: &str) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let url = match &cursor {
+                Some(cursor) => format!("{}{path}?cursor={cursor}", self.base_url),
+                None => format!("{}{path}", self.base_url),
+            };
+            let request = self.http.get(url).build()?;
+            let page: PaginatedResponse<T> = self.send_request(request).await?;
+            items.extend(page.items);
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                return Ok(items);
             }
-            Err(e) => Err(sentry_err!("Failed to connect to HoundDog Cloud: {e}")),
         }
     }
 
-    pub fn authenticate(&self) -> Result<User> {
+    pub async fn authenticate(&self) -> Result<User> {
         let request = self.http.get(format!("{}/users/current/", self.base_url)).build()?;
-        let user = self.send_request(request)?;
+        let user = self.send_request(request).await?;
 
         Ok(user)
     }
 
-    pub fn get_data_elements(&self) -> Result<HashMap<String, DataElement>> {
-        let request = self.http.get(format!("{}/data-elements/", self.base_url)).build()?;
-        let data_elements: PaginatedResponse<DataElement> = self.send_request(request)?;
+    /// Authenticate interactively via OAuth 2.0 Authorization Code with PKCE, as an alternative
+    /// to pasting a pre-provisioned `HOUNDDOG_API_KEY`: generate a `code_verifier`/`code_challenge`
+    /// pair and a random `state`, open the browser to the authorize endpoint, capture the `code`
+    /// on a short-lived localhost redirect listener (rejecting it outright if `state` doesn't come
+    /// back unchanged, since that's the CSRF check), exchange the code for an access token, and
+    /// persist the token so future runs don't need to log in again.
+    pub async fn login(env: &HoundDogEnv, home_dir_path: &Path) -> Result<User> {
+        let code_verifier = pkce::generate_code_verifier()?;
+        let code_challenge = pkce::code_challenge(&code_verifier);
+        let state = pkce::generate_state()?;
+
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let redirect_uri = format!("http://127.0.0.1:{}/callback", listener.local_addr()?.port());
+        let base_url = base_url_for(env);
+
+        let authorize_url = format!(
+            "{base_url}/oauth/authorize?response_type=code&client_id={OAUTH_CLIENT_ID}\
+             &redirect_uri={redirect_uri}&code_challenge={code_challenge}\
+             &code_challenge_method=S256&state={state}"
+        );
+        println!("Opening browser to log in. If it doesn't open, visit:\n{authorize_url}");
+        let _ = open_browser(&authorize_url);
+
+        let code = capture_redirect_code(listener, &state)?;
+
+        let api = Self::new(env, "", home_dir_path)?;
+        let token_request = api
+            .http
+            .post(format!("{base_url}/oauth/token"))
+            .json(&serde_json::json!({
+                "grant_type": "authorization_code",
+                "client_id": OAUTH_CLIENT_ID,
+                "code": code,
+                "code_verifier": code_verifier,
+                "redirect_uri": redirect_uri,
+            }))
+            .build()?;
+        let token: TokenResponse = api.send_request(token_request).await?;
+        save_access_token(home_dir_path, &token.access_token)?;
+
+        Self::new(env, &token.access_token, home_dir_path)?.authenticate().await
+    }
+
+    pub async fn get_data_elements(&self) -> Result<HashMap<String, DataElement>> {
+        let data_elements: Vec<DataElement> = self.get_paginated("/data-elements/").await?;
         Ok(data_elements
-            .items
             .into_iter()
             .map(|data_element| (data_element.id.clone(), data_element))
             .collect())
     }
 
-    pub fn get_data_sinks(&self) -> Result<HashMap<Language, HashMap<String, DataSink>>> {
-        let request = self.http.get(format!("{}/data-sinks/", self.base_url)).build()?;
-        let data_sinks: PaginatedResponse<DataSink> = self.send_request(request)?;
-        Ok(data_sinks.items.into_iter().fold(HashMap::new(), |mut map, data_sink| {
+    pub async fn get_data_sinks(&self) -> Result<HashMap<Language, HashMap<String, DataSink>>> {
+        let data_sinks: Vec<DataSink> = self.get_paginated("/data-sinks/").await?;
+        Ok(data_sinks.into_iter().fold(HashMap::new(), |mut map, data_sink| {
             map.entry(data_sink.language)
                 .or_default()
                 .insert(data_sink.id.clone(), data_sink);
@@ -92,19 +195,165 @@ impl HoundDogCloudApi {
         }))
     }
 
-    pub fn get_sanitizers(&self) -> Result<Vec<Sanitizer>> {
-        let request = self.http.get(format!("{}/sanitizers/", self.base_url)).build()?;
-        let sanitizers: PaginatedResponse<Sanitizer> = self.send_request(request)?;
-        Ok(sanitizers.items)
+    pub async fn get_sanitizers(&self) -> Result<Vec<Sanitizer>> {
+        self.get_paginated("/sanitizers/").await
+    }
+
+    /// Fetch the three rule catalog endpoints concurrently instead of three serial round-trips,
+    /// so a cold scan pays roughly one request's worth of latency instead of three.
+    #[allow(clippy::type_complexity)]
+    pub async fn get_catalog(
+        &self,
+    ) -> Result<(
+        HashMap<String, DataElement>,
+        HashMap<Language, HashMap<String, DataSink>>,
+        Vec<Sanitizer>,
+    )> {
+        tokio::try_join!(self.get_data_elements(), self.get_data_sinks(), self.get_sanitizers())
     }
 
-    pub fn upload_scan_results(&self, scan_results: &ScanResults) -> Result<()> {
+    pub async fn upload_scan_results(&self, scan_results: &ScanResults<'_>) -> Result<()> {
         let request = self
             .http
             .post(format!("{}/scan-results/", self.base_url))
             .body(serde_json::to_string(scan_results)?)
             .build()?;
-        self.send_request(request)?;
+        self.send_request(request).await?;
         Ok(())
     }
+
+    /// Block the current thread on `future`, for the existing synchronous call sites to keep
+    /// working while they migrate onto the async client incrementally.
+    pub fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(future)
+    }
+}
+
+/// The delay a `429` response asked for via its `Retry-After` header (seconds only; this API
+/// doesn't send HTTP-date values), if present and parseable.
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    if response.status() != reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+    response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// Exponential backoff for the `attempt`'th retry (1-indexed), plus jitter up to one base delay
+/// so a burst of clients retrying together doesn't all retry in lockstep.
+fn backoff_delay(attempt: u32) -> Duration {
+    let exponential = BASE_RETRY_DELAY * 2u32.pow(attempt.min(6));
+    exponential + jitter(BASE_RETRY_DELAY)
+}
+
+/// A pseudo-random duration in `[0, max)`, seeded from the wall clock so no extra dependency is
+/// needed just to jitter a retry delay.
+fn jitter(max: Duration) -> Duration {
+    let max_nanos = max.as_nanos() as u64;
+    if max_nanos == 0 {
+        return Duration::ZERO;
+    }
+    let now_nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.subsec_nanos() as u64)
+        .unwrap_or(0);
+    Duration::from_nanos(now_nanos % max_nanos)
+}
+
+fn base_url_for(env: &HoundDogEnv) -> String {
+    match env {
+        HoundDogEnv::Dev => "http://localhost:8000".to_string(),
+        HoundDogEnv::Staging => "https://api.staging.hounddog.ai".to_string(),
+        HoundDogEnv::Prod => "https://api.hounddog.ai".to_string(),
+    }
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+}
+
+#[derive(Deserialize, Serialize)]
+struct StoredCredentials {
+    access_token: String,
+}
+
+fn credentials_path(home_dir_path: &Path) -> PathBuf {
+    home_dir_path.join(".hounddog").join("credentials.json")
+}
+
+/// Persist `access_token` to the user's credentials file so future runs can authenticate without
+/// repeating the browser login.
+fn save_access_token(home_dir_path: &Path, access_token: &str) -> Result<()> {
+    let path = credentials_path(home_dir_path);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let credentials = StoredCredentials { access_token: access_token.to_string() };
+    std::fs::write(path, serde_json::to_string(&credentials)?)?;
+    Ok(())
+}
+
+fn load_access_token(home_dir_path: &Path) -> Option<String> {
+    let contents = std::fs::read_to_string(credentials_path(home_dir_path)).ok()?;
+    let credentials: StoredCredentials = serde_json::from_str(&contents).ok()?;
+    Some(credentials.access_token)
+}
+
+/// Open `url` in the user's default browser with the platform's native opener (`open`, `start`,
+/// or `xdg-open`); the login flow prints the URL regardless, so a failure here isn't fatal on
+/// its own.
+fn open_browser(url: &str) -> Result<()> {
+    let status = if cfg!(target_os = "macos") {
+        std::process::Command::new("open").arg(url).status()
+    } else if cfg!(target_os = "windows") {
+        std::process::Command::new("cmd").args(["/C", "start", "", url]).status()
+    } else {
+        std::process::Command::new("xdg-open").arg(url).status()
+    };
+    status.map(|_| ()).map_err(|e| sentry_err!("Failed to open browser: {e}"))
+}
+
+/// Accept exactly one connection on `listener` (the OAuth redirect), parse the `code`/`state`
+/// query params off its request line, reply with a minimal confirmation page, and return the
+/// authorization code — after verifying `state` matches `expected_state`, since that's what
+/// rules out a CSRF'd redirect.
+fn capture_redirect_code(listener: TcpListener, expected_state: &str) -> Result<String> {
+    let (mut stream, _) = listener.accept()?;
+    let mut request_line = String::new();
+    BufReader::new(stream.try_clone()?).read_line(&mut request_line)?;
+
+    let query = request_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|path| path.splitn(2, '?').nth(1))
+        .unwrap_or_default();
+
+    let mut code = None;
+    let mut state = None;
+    for pair in query.split('&') {
+        match pair.split_once('=') {
+            Some(("code", value)) => code = Some(value.to_string()),
+            Some(("state", value)) => state = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    let body = "<html><body>Login complete. You can close this tab and return to the terminal.\
+                </body></html>";
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\n\r\n{}",
+        body.len(),
+        body
+    )?;
+
+    if state.as_deref() != Some(expected_state) {
+        return Err(sentry_err!("OAuth state mismatch on redirect; aborting login"));
+    }
+    code.ok_or_else(|| sentry_err!("Redirect did not include an authorization code"))
 }
@@ -80,15 +80,37 @@ pub enum Severity {
     Low,
 }
 
+impl Severity {
+    /// Lower the severity by one level, saturating at `Low`. Used when a sanitizer is
+    /// detected on the flow: the finding is still reported but de-emphasised.
+    pub fn downgraded(&self) -> Severity {
+        match self {
+            Severity::Critical => Severity::Medium,
+            Severity::Medium => Severity::Low,
+            Severity::Low => Severity::Low,
+        }
+    }
+
+    /// Whether this severity is at least as severe as `threshold`. `Severity`'s derived `Ord`
+    /// ranks `Critical` lowest (see `Vulnerability::severity`'s construction and the sort in
+    /// `ScanResults::new`), so "at least as severe" reads as "at or before" in that ordering.
+    pub fn meets_or_exceeds(&self, threshold: &Severity) -> bool {
+        self <= threshold
+    }
+}
+
 #[derive(Clone, Debug, Display, Deserialize, Serialize, ValueEnum)]
 #[serde(rename_all = "lowercase")]
 pub enum OutputFormat {
     Cacilian,
     Console,
+    CycloneDx,
     GitLab,
+    LspJson,
     Markdown,
     Json,
     Sarif,
+    SonarQube,
 }
 
 #[derive(
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::env::current_dir;
 use std::fs::canonicalize;
 use std::path::PathBuf;
@@ -8,33 +9,52 @@ use anyhow::Result;
 use clap::{ArgAction, Args, Parser, Subcommand};
 use colored::Colorize;
 
-use utils::file::get_repository_info;
+use utils::file::{build_glob_set, get_repository_info};
 
 use crate::cloud_api::HoundDogCloudApi;
 use crate::enums::{GitProvider, HoundDogEnv, OutputFormat, Severity};
 use crate::error::HoundDogError;
+use crate::reporting::report_scan_results;
 use crate::rules::{get_local_data_elements, get_local_data_sinks, get_local_sanitizers};
+use crate::scanner::matcher::DataElementMatcher;
 use crate::structs::ScanConfig;
+use crate::utils::git::get_git_diff_files;
 use crate::utils::table::print_table;
-use output::cacilian::generate_cacilian_output;
+use output::cacilian::{generate_cacilian_cbor, generate_cacilian_output};
 use output::console::print_console_output;
+use output::cyclonedx::generate_cyclonedx_output;
 use output::gitlab::generate_gitlab_output;
+use output::lsp::{generate_lsp_output, print_lsp_output};
 use output::markdown::generate_markdown_output;
 use output::sarif::generate_sarif_output;
+use output::sonarqube::generate_sonarqube_output;
 
+mod baseline;
+mod bench;
+mod bundle;
 mod cloud_api;
 mod enums;
 mod env;
 mod error;
 mod macros;
 mod output;
+mod reporting;
 mod rules;
 mod scanner;
 mod structs;
 mod utils;
+mod watch;
 
 const SENTRY_DSN: Option<&str> = option_env!("HOUNDDOG_SENTRY_DSN");
 
+/// `hounddog scan` exit codes, for CI jobs gating on them:
+/// - `0`: scan completed, nothing at or above `--fail-severity-threshold`.
+/// - `1`: the scan itself failed (bad arguments, scanner error, etc.) - see stderr. Also used
+///   when `--fail-on-new-only` is set and the scan introduced new findings.
+/// - `2`: the scan completed but surfaced a finding at or above `--fail-severity-threshold`.
+const EXIT_CODE_ERROR: i32 = 1;
+const EXIT_CODE_SEVERITY_THRESHOLD_EXCEEDED: i32 = 2;
+
 #[derive(Debug, Parser)]
 #[command(author = "HoundDog.ai, Inc.", name = "hounddog", version = "1.0.0")]
 struct Cli {
@@ -46,6 +66,8 @@ struct Cli {
 enum Command {
     /// Scan a directory
     Scan(ScanArguments),
+    /// Run benchmark workloads against versioned fixture repositories
+    Bench(BenchArguments),
     Info,
 }
 
@@ -54,6 +76,14 @@ struct ScanArguments {
     /// Target directory to scan
     #[arg(long, default_value = ".", value_name = "DIR")]
     dir: Option<PathBuf>,
+    /// Scan a single file and print its LSP-style diagnostics to stdout, for incremental
+    /// editor/linter integration. Always exits 0, since findings live in the JSON, not the
+    /// exit code.
+    #[arg(long, value_name = "PATH", conflicts_with = "dir")]
+    file: Option<PathBuf>,
+    /// Re-run the scan on filesystem changes and keep running until interrupted (Ctrl-C)
+    #[arg(long, action = ArgAction::SetTrue, conflicts_with = "file")]
+    watch: bool,
     /// Run in debug mode
     #[arg(long, action = ArgAction::SetTrue)]
     debug: bool,
@@ -87,6 +117,29 @@ struct ScanArguments {
     /// Baseline Git commit or branch for diff-aware scanning
     #[arg(long)]
     diff_baseline: Option<String>,
+    /// Path to a persisted fingerprint baseline; findings present in it are reported as
+    /// `existing` rather than `new`
+    #[arg(long)]
+    baseline_file: Option<PathBuf>,
+    /// Overwrite --baseline-file with this scan's findings after reporting the diff
+    #[arg(long, action = ArgAction::SetTrue)]
+    update_baseline: bool,
+    /// With --baseline-file, only fail the scan when new findings were introduced
+    #[arg(long, action = ArgAction::SetTrue)]
+    fail_on_new_only: bool,
+    /// Path to a previously emitted SARIF file (or a plain JSON array of finding hashes); matching
+    /// findings are marked `suppressions: [{ kind: "external" }]` in --output-format sarif output
+    #[arg(long)]
+    sarif_baseline: Option<PathBuf>,
+    /// Emit syntax-highlighted HTML code blocks in the Markdown report instead of plain ones
+    #[arg(long, action = ArgAction::SetTrue)]
+    markdown_syntax_highlighting: bool,
+    /// Restrict the scan to files matching this glob, relative to the scan root (repeatable)
+    #[arg(long, num_args = 1.., value_name = "GLOB", value_delimiter = ' ')]
+    include: Vec<String>,
+    /// Exclude files matching this glob from the scan, relative to the scan root (repeatable)
+    #[arg(long, num_args = 1.., value_name = "GLOB", value_delimiter = ' ')]
+    exclude: Vec<String>,
     /// Include sensitive datamap in the output
     #[arg(long)]
     sensitivity_datamap: Option<bool>,
@@ -113,14 +166,28 @@ struct ScanArguments {
     ai_model: String,
 }
 
-fn scan(env: &env::Environment, args: &ScanArguments) -> Result<()> {
+/// Runs a scan and reports whether a finding at or above `--fail-severity-threshold` was
+/// surfaced, so `main` can translate that into [`EXIT_CODE_SEVERITY_THRESHOLD_EXCEEDED`].
+fn scan(env: &env::Environment, args: &ScanArguments) -> Result<bool> {
     let is_debug = env.debug || args.debug;
 
-    let repository_path = match &args.dir {
-        Some(path) => {
-            canonicalize(&path).map_err(|e| err!("Bad directory '{}': {e}", path.display()))?
-        }
-        None => canonicalize(&current_dir()?)?,
+    let single_file = args
+        .file
+        .as_ref()
+        .map(|path| canonicalize(path).map_err(|e| err!("Bad file '{}': {e}", path.display())))
+        .transpose()?;
+
+    let repository_path = match &single_file {
+        Some(file) => file
+            .parent()
+            .ok_or_else(|| err!("File '{}' has no parent directory", file.display()))?
+            .to_path_buf(),
+        None => match &args.dir {
+            Some(path) => {
+                canonicalize(&path).map_err(|e| err!("Bad directory '{}': {e}", path.display()))?
+            }
+            None => canonicalize(&current_dir()?)?,
+        },
     };
     let rules_dir_path = match env.hounddog_env {
         HoundDogEnv::Dev => env.home_dir_path.join("hounddog-workspace/hounddog/rules"),
@@ -142,8 +209,9 @@ fn scan(env: &env::Environment, args: &ScanArguments) -> Result<()> {
         None
     } else {
         print_dbg!(is_debug, "Detected HOUNDDOG_API_KEY. Authenticating ...");
-        let api = HoundDogCloudApi::new(&env.hounddog_env, &env.hounddog_api_key)?;
-        let user = api.authenticate()?;
+        let api =
+            HoundDogCloudApi::new(&env.hounddog_env, &env.hounddog_api_key, &env.home_dir_path)?;
+        let user = HoundDogCloudApi::block_on(api.authenticate())?;
         print_dbg!(is_debug, "Authenticated user in organization {}", &user.org_name);
 
         sentry::configure_scope(|scope| {
@@ -154,17 +222,13 @@ fn scan(env: &env::Environment, args: &ScanArguments) -> Result<()> {
     };
 
     println!("Fetching scanner rules ...");
-    let mut data_elements = match &cloud {
-        Some(api) => api.get_data_elements()?,
-        None => get_local_data_elements(&rules_dir_path)?,
-    };
-    let mut data_sinks = match &cloud {
-        Some(api) => api.get_data_sinks()?,
-        None => get_local_data_sinks(&rules_dir_path)?,
-    };
-    let sanitizers = match &cloud {
-        Some(api) => api.get_sanitizers()?,
-        None => get_local_sanitizers(&rules_dir_path)?,
+    let (mut data_elements, mut data_sinks, sanitizers) = match &cloud {
+        Some(api) => HoundDogCloudApi::block_on(api.get_catalog())?,
+        None => (
+            get_local_data_elements(&rules_dir_path)?,
+            get_local_data_sinks(&rules_dir_path)?,
+            get_local_sanitizers(&rules_dir_path)?,
+        ),
     };
     print_dbg!(is_debug, "Found {} data elements", data_elements.len());
     print_dbg!(is_debug, "Found {} data sinks", data_sinks.values().flatten().count());
@@ -180,6 +244,15 @@ fn scan(env: &env::Environment, args: &ScanArguments) -> Result<()> {
         });
     });
 
+    let data_element_matcher = DataElementMatcher::build(&data_elements);
+    let include_globs = match &single_file {
+        Some(file) => {
+            let relative_path = file.strip_prefix(&repository_path).unwrap_or(file);
+            build_glob_set(&[relative_path.display().to_string()])?
+        }
+        None => build_glob_set(&args.include)?,
+    };
+    let exclude_globs = build_glob_set(&args.exclude)?;
     let config = ScanConfig {
         is_debug,
         is_paid_features_enabled: cloud.is_some(),
@@ -193,17 +266,40 @@ fn scan(env: &env::Environment, args: &ScanArguments) -> Result<()> {
         skip_data_sinks: args.skip_data_sink.iter().map(|id| id.to_lowercase()).collect(),
         skip_occurrences: args.skip_occurrence.iter().map(|h| h.to_uppercase()).collect(),
         skip_vulnerabilities: args.skip_vulnerability.iter().map(|h| h.to_uppercase()).collect(),
+        include_severity: args.include_severity.clone(),
+        fail_severity_threshold: args.fail_severity_threshold.clone(),
+        graphql: Default::default(),
+        targets: Vec::new(),
+        diff_baseline: args.diff_baseline.clone(),
+        empty_diff_mode: Default::default(),
+        unmatched_path_mode: Default::default(),
+        markdown_syntax_highlighting: args.markdown_syntax_highlighting,
+        data_element_matcher,
+        include_globs,
+        exclude_globs,
     };
     println!("Running scan (this might take a while) ...");
     let start_time = Instant::now();
     let results = scanner::run_scan(&config)?;
     println!("Scan completed in {} seconds.\n", start_time.elapsed().as_secs_f64());
 
+    if single_file.is_some() {
+        // `--file` is the incremental editor/linter path: print diagnostics and always succeed,
+        // since findings belong in the JSON, not the exit code.
+        print_lsp_output(&results)?;
+        return Ok(false);
+    }
+
     print_console_output(&results)?;
 
+    if args.watch {
+        return watch::watch(&config).map(|_| false);
+    }
+
     match config.output_format {
         OutputFormat::Cacilian => {
             generate_cacilian_output(&results)?;
+            generate_cacilian_cbor(&results)?;
         }
         OutputFormat::Markdown => {
             generate_markdown_output(&results)?;
@@ -212,10 +308,154 @@ fn scan(env: &env::Environment, args: &ScanArguments) -> Result<()> {
             generate_gitlab_output(&results)?;
         }
         OutputFormat::Sarif => {
-            generate_sarif_output(&results)?;
+            let suppressed_hashes = match &args.sarif_baseline {
+                Some(path) => output::sarif::load_sarif_baseline(path)?,
+                None => HashSet::new(),
+            };
+            generate_sarif_output(&results, &suppressed_hashes)?;
+        }
+        OutputFormat::SonarQube => {
+            generate_sonarqube_output(&results)?;
+        }
+        OutputFormat::LspJson => {
+            generate_lsp_output(&results)?;
+        }
+        OutputFormat::CycloneDx => {
+            generate_cyclonedx_output(&results)?;
         }
         _ => {}
     }
+
+    if let Some(baseline_file) = &args.baseline_file {
+        let baseline = baseline::load_baseline(baseline_file)?;
+        let diff = baseline::diff_against_baseline(&results, &baseline);
+        print_header!("Baseline Comparison");
+        println!(
+            "{} new, {} existing, {} fixed",
+            diff.new.len(),
+            diff.existing.len(),
+            diff.fixed.len()
+        );
+        if args.update_baseline {
+            baseline::write_baseline(baseline_file, &results)?;
+        }
+        if args.fail_on_new_only && !diff.new.is_empty() {
+            process::exit(1);
+        }
+    }
+
+    if env.ci_type.is_some() {
+        let changed_files = match &config.diff_baseline {
+            Some(baseline) => {
+                let repo = git2::Repository::open(&config.repository.path)?;
+                Some(
+                    get_git_diff_files(&repo, Some(baseline))?
+                        .into_iter()
+                        .filter_map(|path| path.to_str().map(str::to_string))
+                        .collect::<Vec<_>>(),
+                )
+            }
+            None => None,
+        };
+        if let Err(e) = report_scan_results(&results, changed_files.as_deref()) {
+            print_warn!("Failed to post scan results to Git provider: {e}");
+        }
+    }
+
+    Ok(results.exceeds_fail_severity_threshold)
+}
+
+#[derive(Args, Debug)]
+struct BenchArguments {
+    /// Workload manifest JSON files to run (repeatable)
+    #[arg(long, num_args = 1.., value_name = "FILE", value_delimiter = ' ')]
+    workload: Vec<PathBuf>,
+    /// Machine-readable JSON summary filename [default: hounddog-bench-{datetime}.json]
+    #[arg(long)]
+    output_filename: Option<String>,
+}
+
+fn bench(env: &env::Environment, args: &BenchArguments) -> Result<()> {
+    let rules_dir_path = match env.hounddog_env {
+        HoundDogEnv::Dev => env.home_dir_path.join("hounddog-workspace/hounddog/rules"),
+        _ => PathBuf::from(&env.hounddog_rules_dir),
+    };
+
+    let mut results = Vec::new();
+    for manifest_path in &args.workload {
+        let workload = bench::load_workload(manifest_path)
+            .map_err(|e| err!("Bad workload manifest '{}': {e}", manifest_path.display()))?;
+
+        let repository = get_repository_info(&workload.target, &env.ci_type)?;
+        let data_elements = get_local_data_elements(&rules_dir_path)?;
+        let data_sinks = get_local_data_sinks(&rules_dir_path)?;
+        let sanitizers = get_local_sanitizers(&rules_dir_path)?;
+        let data_element_matcher = DataElementMatcher::build(&data_elements);
+
+        let config = ScanConfig {
+            is_debug: false,
+            is_paid_features_enabled: false,
+            repository,
+            data_elements,
+            data_sinks,
+            sanitizers,
+            output_filename: None,
+            output_format: OutputFormat::Console,
+            skip_data_elements: Default::default(),
+            skip_data_sinks: Default::default(),
+            skip_occurrences: Default::default(),
+            skip_vulnerabilities: Default::default(),
+            include_severity: Vec::new(),
+            fail_severity_threshold: None,
+            graphql: Default::default(),
+            targets: Vec::new(),
+            diff_baseline: None,
+            empty_diff_mode: Default::default(),
+            unmatched_path_mode: Default::default(),
+            markdown_syntax_highlighting: false,
+            data_element_matcher,
+            include_globs: None,
+            exclude_globs: None,
+        };
+
+        let result = bench::run_workload(&workload, &config)?;
+        println!(
+            "{:<24} {:>6} files {:>9} lines  parse {:>7.3}s  visit {:>7.3}s  taint {:>7.3}s  \
+             {:>9.1} files/s  {:>10.1} lines/s",
+            result.label,
+            result.file_count,
+            result.line_count,
+            result.parse_seconds,
+            result.visit_seconds,
+            result.taint_resolution_seconds,
+            result.files_per_sec,
+            result.lines_per_sec,
+        );
+        if result.occurrences_matched_expected == Some(false)
+            || result.vulnerabilities_matched_expected == Some(false)
+        {
+            print_warn!(
+                "{}: expected {:?} occurrences / {:?} vulnerabilities, found {} / {}",
+                result.label,
+                workload.expected_occurrences,
+                workload.expected_vulnerabilities,
+                result.occurrences_found,
+                result.vulnerabilities_found,
+            );
+        }
+        results.push(result);
+    }
+
+    let now = chrono::offset::Local::now();
+    let summary_path = match &args.output_filename {
+        Some(path) => PathBuf::from(path),
+        None => {
+            let file_name = now.format("hounddog-bench-%Y-%m-%d-%H-%M-%S.json").to_string();
+            current_dir()?.join(file_name)
+        }
+    };
+    serde_json::to_writer_pretty(std::fs::File::create(&summary_path)?, &results)?;
+    println!("file://{}", summary_path.display());
     Ok(())
 }
 
@@ -241,11 +481,17 @@ fn main() -> Result<()> {
 
     let command_result = match Cli::parse().command {
         Some(Command::Scan(args)) => scan(&env, &args),
-        Some(Command::Info) => print_hounddog_info(),
-        None => Ok(()),
+        Some(Command::Bench(args)) => bench(&env, &args).map(|_| false),
+        Some(Command::Info) => print_hounddog_info().map(|_| false),
+        None => Ok(false),
     };
     match command_result {
-        Ok(_) => Ok(()),
+        Ok(threshold_exceeded) => {
+            if threshold_exceeded {
+                process::exit(EXIT_CODE_SEVERITY_THRESHOLD_EXCEEDED);
+            }
+            Ok(())
+        }
         Err(err) => {
             if let Some(scanner_err) = err.downcast_ref::<HoundDogError>() {
                 if scanner_err.sentry {
@@ -257,7 +503,7 @@ fn main() -> Result<()> {
             } else {
                 print_err!("{}", err);
             }
-            process::exit(1);
+            process::exit(EXIT_CODE_ERROR);
         }
     }
 }
@@ -1,10 +1,13 @@
+use std::fs::File;
+use std::path::Path;
+
 use anyhow::Result;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::enums::{Sensitivity, Severity, Source};
 use crate::structs::ScanResults;
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct DataElement {
     id: String,
     name: String,
@@ -13,14 +16,14 @@ struct DataElement {
     is_ai_generated: bool,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct DataElementOccurrence {
     data_element: String,
     count: usize,
     locations: Vec<DataElementOccurrenceLocation>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct DataElementOccurrenceLocation {
     hash: String,
     code_segment: String,
@@ -29,7 +32,7 @@ struct DataElementOccurrenceLocation {
     category: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct VulnerabilityRule {
     id: String,
     name: String,
@@ -39,7 +42,7 @@ struct VulnerabilityRule {
     owasp: Vec<String>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct Vulnerability {
     hash: String,
     code_segment: String,
@@ -52,7 +55,7 @@ struct Vulnerability {
     data_elements: Vec<String>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 pub struct CacilianJson {
     repository: String,
     repository_url: String,
@@ -64,16 +67,8 @@ pub struct CacilianJson {
     vulnerabilities: Vec<Vulnerability>,
 }
 
-pub fn generate_cacilian_output(results: &ScanResults) -> Result<CacilianJson> {
-    let now = chrono::offset::Local::now();
-    let file_path = match &results.output_filename {
-        Some(path) => &results.repository.path.join(path),
-        None => &results
-            .repository
-            .path
-            .join(now.format("hounddog-%Y-%m-%d-%H-%M-%S.cacilian.json").to_string()),
-    };
-    let cacilian_json = CacilianJson {
+fn build_cacilian_json(results: &ScanResults) -> CacilianJson {
+    CacilianJson {
         repository: results.repository.name.clone(),
         repository_url: results.repository.base_url.clone(),
         branch: results.repository.branch.clone(),
@@ -136,8 +131,44 @@ pub fn generate_cacilian_output(results: &ScanResults) -> Result<CacilianJson> {
                 data_elements: vul.data_element_names.clone(),
             })
             .collect(),
+    }
+}
+
+pub fn generate_cacilian_output(results: &ScanResults) -> Result<CacilianJson> {
+    let now = chrono::offset::Local::now();
+    let file_path = match &results.output_filename {
+        Some(path) => &results.repository.path.join(path),
+        None => &results
+            .repository
+            .path
+            .join(now.format("hounddog-%Y-%m-%d-%H-%M-%S.cacilian.json").to_string()),
+    };
+    let cacilian_json = build_cacilian_json(results);
+    serde_json::to_writer_pretty(File::create(file_path)?, &cacilian_json)?;
+    println!("file://{}", file_path.display());
+    Ok(cacilian_json)
+}
+
+/// Write the same report `generate_cacilian_output` produces as a compact CBOR file alongside
+/// the JSON one, for integrations that would rather parse a binary format than pretty-printed
+/// JSON.
+pub fn generate_cacilian_cbor(results: &ScanResults) -> Result<CacilianJson> {
+    let now = chrono::offset::Local::now();
+    let file_path = match &results.output_filename {
+        Some(path) => &results.repository.path.join(path).with_extension("cbor"),
+        None => &results
+            .repository
+            .path
+            .join(now.format("hounddog-%Y-%m-%d-%H-%M-%S.cacilian.cbor").to_string()),
     };
-    serde_json::to_writer_pretty(std::fs::File::create(file_path)?, &cacilian_json)?;
+    let cacilian_json = build_cacilian_json(results);
+    serde_cbor::to_writer(File::create(file_path)?, &cacilian_json)?;
     println!("file://{}", file_path.display());
     Ok(cacilian_json)
 }
+
+/// Decode a `.cacilian.cbor` file written by `generate_cacilian_cbor` back into the report it
+/// encoded.
+pub fn read_cacilian_cbor(path: &Path) -> Result<CacilianJson> {
+    Ok(serde_cbor::from_reader(File::open(path)?)?)
+}
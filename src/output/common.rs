@@ -146,3 +146,112 @@ pub fn get_dataflow_visualizations(
     });
     elem_id_to_mermaid_diagram
 }
+
+/// Shared `ScanConfig`/`Vulnerability`/`DataElementOccurrence` fixtures for the `output` modules'
+/// test suites, so the shape of a "typical" scan result lives in one place instead of being
+/// copy-pasted (and drifting) across every `output::*::tests` module.
+#[cfg(test)]
+pub mod test_fixtures {
+    use crate::enums::{OutputFormat, Source};
+    use crate::structs::{Repository, ScanConfig};
+
+    use super::*;
+
+    pub fn test_config(output_format: OutputFormat) -> ScanConfig {
+        ScanConfig {
+            is_debug: false,
+            is_paid_features_enabled: false,
+            repository: Repository {
+                path: std::env::temp_dir(),
+                base_url: "https://github.com/org/repo".to_string(),
+                name: "org/repo".to_string(),
+                branch: "main".to_string(),
+                commit: "abc123".to_string(),
+                git_provider: None,
+                per_lang_file_stats: HashMap::new(),
+                total_file_stats: Default::default(),
+            },
+            data_elements: HashMap::new(),
+            data_sinks: HashMap::new(),
+            sanitizers: vec![],
+            output_filename: None,
+            output_format,
+            skip_data_elements: HashSet::new(),
+            skip_data_sinks: HashSet::new(),
+            skip_occurrences: HashSet::new(),
+            skip_vulnerabilities: HashSet::new(),
+            include_severity: Vec::new(),
+            fail_severity_threshold: None,
+            graphql: Default::default(),
+            targets: Vec::new(),
+            diff_baseline: None,
+            empty_diff_mode: Default::default(),
+            unmatched_path_mode: Default::default(),
+            markdown_syntax_highlighting: false,
+            data_element_matcher: crate::scanner::matcher::DataElementMatcher::build(
+                &HashMap::new(),
+            ),
+            include_globs: None,
+            exclude_globs: None,
+        }
+    }
+
+    pub fn test_vulnerability() -> Vulnerability {
+        Vulnerability {
+            data_sink_id: "log-sensitive-data".to_string(),
+            data_element_ids: vec!["email".to_string()],
+            data_element_names: vec!["Email".to_string()],
+            hash: "deadbeef".to_string(),
+            description: "Sensitive data is logged in plaintext.".to_string(),
+            severity: Severity::Critical,
+            language: Language::Python,
+            code_segment: "logger.info(user.email)".to_string(),
+            absolute_file_path: "/repo/app.py".to_string(),
+            relative_file_path: "app.py".to_string(),
+            line_start: 10,
+            line_end: 10,
+            column_start: 1,
+            column_end: 20,
+            url_link: "https://github.com/org/repo/blob/abc123/app.py#L10".to_string(),
+            cwe: vec!["CWE-532".to_string()],
+            owasp: vec!["A09:2021".to_string()],
+            sanitized_by: None,
+            code_frame: None,
+        }
+    }
+
+    pub fn test_occurrence() -> DataElementOccurrence {
+        DataElementOccurrence {
+            data_element_id: "email".to_string(),
+            data_element_name: "Email".to_string(),
+            sensitivity: Sensitivity::Critical,
+            source: Source::HoundDog,
+            hash: "cafebabe".to_string(),
+            code_segment: "user.email".to_string(),
+            language: Language::Python,
+            absolute_file_path: "/repo/app.py".to_string(),
+            relative_file_path: "app.py".to_string(),
+            line_start: 5,
+            line_end: 5,
+            column_start: 1,
+            column_end: 10,
+            url_link: "https://github.com/org/repo/blob/abc123/app.py#L5".to_string(),
+            tags: vec![],
+        }
+    }
+
+    pub fn test_data_element() -> DataElement {
+        DataElement {
+            id: "email".to_string(),
+            name: "Email".to_string(),
+            description: String::new(),
+            include_patterns: vec![],
+            exclude_patterns: vec![],
+            is_enabled: true,
+            sensitivity: Sensitivity::Critical,
+            source: Source::HoundDog,
+            tags: vec!["pii".to_string()],
+            validator: None,
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use anyhow::Result;
 use colored::Colorize;
 
+use crate::enums::Language;
+use crate::output::highlight::highlight_console_lines;
 use crate::structs::ScanResults;
 use crate::{console_label, console_note, console_text, console_url, print_header, print_table};
 
@@ -34,7 +36,7 @@ pub fn print_console_output(results: &ScanResults) -> Result<()> {
                 )
             );
             println!("{}", console_url!(v.url_link));
-            print_code_block(&v.code_segment, v.line_start, v.line_end);
+            print_code_block(&v.code_segment, v.line_start, v.line_end, &v.language);
             print_remediation(results.get_remediation(&v.language, &v.data_sink_id));
             println!("{}", console_note!("CWE/OWASP: {}", v.security_categories()));
             println!("{}", console_note!("To ignore, use flag --skip-vulnerability={}", v.hash));
@@ -63,10 +65,10 @@ pub fn print_console_output(results: &ScanResults) -> Result<()> {
     Ok(())
 }
 
-fn print_code_block(code: &str, line_start: usize, line_end: usize) {
+fn print_code_block(code: &str, line_start: usize, line_end: usize, language: &Language) {
     let max_line_num_width = line_end.to_string().len();
 
-    for (line_num, line) in code.lines().enumerate() {
+    for (line_num, line) in highlight_console_lines(code, language).iter().enumerate() {
         println!(
             "{:<width$} {} {}",
             (line_start + line_num).to_string().truecolor(92, 145, 255),
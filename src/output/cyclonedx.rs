@@ -0,0 +1,245 @@
+use anyhow::Result;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::enums::Severity;
+use crate::structs::{DataElement, ScanResults, Vulnerability};
+
+const CYCLONEDX_SPEC_VERSION: &str = "1.5";
+
+#[derive(Serialize)]
+pub struct CycloneDxBom {
+    #[serde(rename = "bomFormat")]
+    bom_format: String,
+    #[serde(rename = "specVersion")]
+    spec_version: String,
+    #[serde(rename = "serialNumber")]
+    serial_number: String,
+    version: u32,
+    components: Vec<CycloneDxComponent>,
+    vulnerabilities: Vec<CycloneDxVulnerability>,
+}
+
+#[derive(Serialize)]
+struct CycloneDxComponent {
+    #[serde(rename = "type")]
+    component_type: String,
+    #[serde(rename = "bom-ref")]
+    bom_ref: String,
+    name: String,
+    properties: Vec<CycloneDxProperty>,
+    evidence: CycloneDxEvidence,
+}
+
+#[derive(Serialize)]
+struct CycloneDxProperty {
+    name: String,
+    value: String,
+}
+
+#[derive(Serialize)]
+struct CycloneDxEvidence {
+    occurrences: Vec<CycloneDxOccurrence>,
+}
+
+#[derive(Serialize)]
+struct CycloneDxOccurrence {
+    location: String,
+}
+
+#[derive(Serialize)]
+struct CycloneDxVulnerability {
+    #[serde(rename = "bom-ref")]
+    bom_ref: String,
+    id: String,
+    description: String,
+    ratings: Vec<CycloneDxRating>,
+    cwes: Vec<u32>,
+    affects: Vec<CycloneDxAffect>,
+    properties: Vec<CycloneDxProperty>,
+}
+
+#[derive(Serialize)]
+struct CycloneDxRating {
+    severity: String,
+}
+
+#[derive(Serialize)]
+struct CycloneDxAffect {
+    #[serde(rename = "ref")]
+    component_ref: String,
+}
+
+fn bom_ref_for(data_element: &DataElement) -> String {
+    format!("data-element:{}", data_element.id)
+}
+
+fn severity_to_cyclonedx(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "critical",
+        Severity::Medium => "medium",
+        Severity::Low => "low",
+    }
+}
+
+/// Parse the numeric id out of a `CWE-NNN` string, since CycloneDX's `cwes` field is an array of
+/// bare integers rather than the `CWE-` prefixed strings our data sinks carry.
+fn parse_cwe_number(cwe: &str) -> Option<u32> {
+    cwe.trim_start_matches("CWE-").parse().ok()
+}
+
+/// A deterministic `urn:uuid:`-shaped serial number derived from the repository commit, since
+/// there's no UUID-generation dependency declared to mint a random one and the BOM should be
+/// stable for the same scan.
+fn serial_number_for(commit: &str) -> String {
+    let digest = Sha256::digest(commit.as_bytes());
+    format!(
+        "urn:uuid:{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-\
+         {:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+        digest[0],
+        digest[1],
+        digest[2],
+        digest[3],
+        digest[4],
+        digest[5],
+        digest[6],
+        digest[7],
+        digest[8],
+        digest[9],
+        digest[10],
+        digest[11],
+        digest[12],
+        digest[13],
+        digest[14],
+        digest[15],
+    )
+}
+
+fn components_for(results: &ScanResults) -> Vec<CycloneDxComponent> {
+    let mut components: Vec<CycloneDxComponent> = results
+        .data_elements
+        .values()
+        .map(|data_element| {
+            let mut properties = vec![CycloneDxProperty {
+                name: "hounddog:sensitivity".to_string(),
+                value: data_element.sensitivity.to_string(),
+            }];
+            properties.extend(data_element.tags.iter().map(|tag| CycloneDxProperty {
+                name: "hounddog:tag".to_string(),
+                value: tag.clone(),
+            }));
+
+            let occurrences = results
+                .occurrences
+                .iter()
+                .filter(|occurrence| occurrence.data_element_id == data_element.id)
+                .map(|occurrence| CycloneDxOccurrence {
+                    location: format!(
+                        "pkg:hounddog/occurrence@{}?line={}&column={}",
+                        occurrence.relative_file_path,
+                        occurrence.line_start,
+                        occurrence.column_start,
+                    ),
+                })
+                .collect();
+
+            CycloneDxComponent {
+                component_type: "data".to_string(),
+                bom_ref: bom_ref_for(data_element),
+                name: data_element.name.clone(),
+                properties,
+                evidence: CycloneDxEvidence { occurrences },
+            }
+        })
+        .collect();
+    components.sort_by(|a, b| a.bom_ref.cmp(&b.bom_ref));
+    components
+}
+
+fn vulnerability_for(
+    results: &ScanResults,
+    vulnerability: &Vulnerability,
+) -> CycloneDxVulnerability {
+    let affects = vulnerability
+        .data_element_ids
+        .iter()
+        .filter_map(|id| results.data_elements.get(id))
+        .map(|data_element| CycloneDxAffect { component_ref: bom_ref_for(data_element) })
+        .collect();
+
+    CycloneDxVulnerability {
+        bom_ref: format!("vulnerability:{}", vulnerability.hash),
+        id: vulnerability.hash.clone(),
+        description: vulnerability.description.clone(),
+        ratings: vec![CycloneDxRating {
+            severity: severity_to_cyclonedx(&vulnerability.severity).to_string(),
+        }],
+        cwes: vulnerability.cwe.iter().filter_map(|cwe| parse_cwe_number(cwe)).collect(),
+        affects,
+        properties: vulnerability
+            .owasp
+            .iter()
+            .map(|owasp| CycloneDxProperty {
+                name: "hounddog:owasp".to_string(),
+                value: owasp.clone(),
+            })
+            .collect(),
+    }
+}
+
+pub fn generate_cyclonedx_output(results: &ScanResults) -> Result<CycloneDxBom> {
+    println!("Generating CycloneDX output ...");
+    let now = chrono::offset::Local::now();
+    let file_path = match &results.output_filename {
+        Some(path) => &results.repository.path.join(path),
+        None => &results
+            .repository
+            .path
+            .join(now.format("hounddog-%Y-%m-%d-%H-%M-%S.cyclonedx.json").to_string()),
+    };
+
+    let bom = CycloneDxBom {
+        bom_format: "CycloneDX".to_string(),
+        spec_version: CYCLONEDX_SPEC_VERSION.to_string(),
+        serial_number: serial_number_for(&results.repository.commit),
+        version: 1,
+        components: components_for(results),
+        vulnerabilities: results
+            .vulnerabilities
+            .iter()
+            .map(|vulnerability| vulnerability_for(results, vulnerability))
+            .collect(),
+    };
+
+    serde_json::to_writer_pretty(std::fs::File::create(file_path)?, &bom)?;
+    println!("file://{}", file_path.display());
+    Ok(bom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::common::test_fixtures::{test_config, test_data_element, test_vulnerability};
+
+    #[test]
+    fn emits_components_and_vulnerabilities_for_sensitive_data() {
+        let mut config = test_config(crate::enums::OutputFormat::CycloneDx);
+        config.data_elements.insert("email".to_string(), test_data_element());
+        let results = ScanResults::new(&config, vec![test_vulnerability()], vec![]);
+
+        let bom = generate_cyclonedx_output(&results).unwrap();
+        let value = serde_json::to_value(&bom).unwrap();
+
+        assert_eq!(value["bomFormat"], "CycloneDX");
+        assert_eq!(value["specVersion"], "1.5");
+        let component = &value["components"][0];
+        assert_eq!(component["type"], "data");
+        assert_eq!(component["bom-ref"], "data-element:email");
+
+        let vulnerability = &value["vulnerabilities"][0];
+        assert_eq!(vulnerability["id"], "deadbeef");
+        assert_eq!(vulnerability["ratings"][0]["severity"], "critical");
+        assert_eq!(vulnerability["cwes"][0], 532);
+        assert_eq!(vulnerability["affects"][0]["ref"], "data-element:email");
+    }
+}
@@ -1,10 +1,193 @@
 use anyhow::Result;
+use serde::Serialize;
 
-use crate::structs::ScanResults;
+use crate::enums::Severity;
+use crate::structs::{ScanResults, Vulnerability};
 
-pub struct GitlabJson;
+const GITLAB_SCHEMA_VERSION: &str = "15.0.0";
+const SCANNER_VERSION: &str = "1.0.0";
+
+#[derive(Serialize)]
+pub struct GitlabJson {
+    version: String,
+    scan: GitlabScan,
+    vulnerabilities: Vec<GitlabVulnerability>,
+}
+
+#[derive(Serialize)]
+struct GitlabScan {
+    analyzer: GitlabScanner,
+    scanner: GitlabScanner,
+    #[serde(rename = "type")]
+    scan_type: String,
+    start_time: String,
+    end_time: String,
+}
+
+#[derive(Clone, Serialize)]
+struct GitlabScanner {
+    id: String,
+    name: String,
+    url: String,
+    version: String,
+}
+
+#[derive(Serialize)]
+struct GitlabVulnerability {
+    id: String,
+    category: String,
+    name: String,
+    message: String,
+    description: String,
+    severity: String,
+    location: GitlabLocation,
+    identifiers: Vec<GitlabIdentifier>,
+}
+
+#[derive(Serialize)]
+struct GitlabLocation {
+    file: String,
+    #[serde(rename = "start_line")]
+    start_line: usize,
+    #[serde(rename = "end_line")]
+    end_line: usize,
+}
+
+#[derive(Serialize)]
+struct GitlabIdentifier {
+    #[serde(rename = "type")]
+    identifier_type: String,
+    name: String,
+    value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+
+fn severity_to_gitlab(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "Critical",
+        Severity::Medium => "Medium",
+        Severity::Low => "Low",
+    }
+}
+
+/// Rule, sensitive-`DataElement`, CWE, and OWASP identifiers for a vulnerability, so the GitLab
+/// security widget can group and link findings back to the sink rule, the data it exposed, and
+/// the standard weakness/risk classifications the data sink was tagged with.
+fn identifiers_for(vulnerability: &Vulnerability) -> Vec<GitlabIdentifier> {
+    let mut identifiers = vec![GitlabIdentifier {
+        identifier_type: "hounddog_rule_id".to_string(),
+        name: vulnerability.data_sink_id.clone(),
+        value: vulnerability.data_sink_id.clone(),
+        url: None,
+    }];
+    identifiers.extend(vulnerability.data_element_ids.iter().map(|id| GitlabIdentifier {
+        identifier_type: "hounddog_data_element_id".to_string(),
+        name: id.clone(),
+        value: id.clone(),
+        url: None,
+    }));
+    identifiers.extend(vulnerability.cwe.iter().map(|cwe| {
+        let number = cwe.trim_start_matches("CWE-").to_string();
+        GitlabIdentifier {
+            identifier_type: "cwe".to_string(),
+            name: cwe.clone(),
+            url: Some(format!("https://cwe.mitre.org/data/definitions/{number}.html")),
+            value: number,
+        }
+    }));
+    identifiers.extend(vulnerability.owasp.iter().map(|owasp| GitlabIdentifier {
+        identifier_type: "owasp".to_string(),
+        name: owasp.clone(),
+        value: owasp.clone(),
+        url: None,
+    }));
+    identifiers
+}
 
 pub fn generate_gitlab_output(results: &ScanResults) -> Result<GitlabJson> {
     println!("Generating GitLab JSON file ...");
-    Ok(GitlabJson)
+    let now = chrono::offset::Local::now();
+    let file_path = match &results.output_filename {
+        Some(path) => &results.repository.path.join(path),
+        None => &results
+            .repository
+            .path
+            .join(now.format("hounddog-%Y-%m-%d-%H-%M-%S.gitlab.json").to_string()),
+    };
+
+    let scanner = GitlabScanner {
+        id: "hounddog".to_string(),
+        name: "HoundDog.ai".to_string(),
+        url: "https://hounddog.ai".to_string(),
+        version: SCANNER_VERSION.to_string(),
+    };
+    let gitlab_json = GitlabJson {
+        version: GITLAB_SCHEMA_VERSION.to_string(),
+        scan: GitlabScan {
+            analyzer: scanner.clone(),
+            scanner,
+            scan_type: "sast".to_string(),
+            start_time: now.to_rfc3339(),
+            end_time: now.to_rfc3339(),
+        },
+        vulnerabilities: results
+            .vulnerabilities
+            .iter()
+            .map(|vulnerability| GitlabVulnerability {
+                id: vulnerability.hash.clone(),
+                category: "sast".to_string(),
+                name: vulnerability.data_sink_id.clone(),
+                message: vulnerability.description.clone(),
+                description: vulnerability.description.clone(),
+                severity: severity_to_gitlab(&vulnerability.severity).to_string(),
+                location: GitlabLocation {
+                    file: vulnerability.relative_file_path.clone(),
+                    start_line: vulnerability.line_start,
+                    end_line: vulnerability.line_end,
+                },
+                identifiers: identifiers_for(vulnerability),
+            })
+            .collect(),
+    };
+
+    serde_json::to_writer_pretty(std::fs::File::create(file_path)?, &gitlab_json)?;
+    println!("file://{}", file_path.display());
+    Ok(gitlab_json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::common::test_fixtures::{test_config, test_vulnerability};
+
+    #[test]
+    fn emits_the_gitlab_sast_report_shape() {
+        let config = test_config(crate::enums::OutputFormat::GitLab);
+        let results = ScanResults::new(&config, vec![test_vulnerability()], vec![]);
+
+        let gitlab_json = generate_gitlab_output(&results).unwrap();
+        let value = serde_json::to_value(&gitlab_json).unwrap();
+
+        assert_eq!(value["version"], "15.0.0");
+        assert_eq!(value["scan"]["type"], "sast");
+        assert_eq!(value["scan"]["scanner"]["id"], "hounddog");
+        let vulnerability = &value["vulnerabilities"][0];
+        assert_eq!(vulnerability["id"], "deadbeef");
+        assert_eq!(vulnerability["category"], "sast");
+        assert_eq!(vulnerability["severity"], "Critical");
+        assert_eq!(vulnerability["location"]["file"], "app.py");
+        assert_eq!(vulnerability["location"]["start_line"], 10);
+        assert_eq!(vulnerability["identifiers"][0]["type"], "hounddog_rule_id");
+
+        let identifiers = vulnerability["identifiers"].as_array().unwrap();
+        let cwe_identifier = identifiers.iter().find(|id| id["type"] == "cwe").unwrap();
+        assert_eq!(cwe_identifier["name"], "CWE-532");
+        assert_eq!(cwe_identifier["value"], "532");
+        assert_eq!(cwe_identifier["url"], "https://cwe.mitre.org/data/definitions/532.html");
+
+        let owasp_identifier = identifiers.iter().find(|id| id["type"] == "owasp").unwrap();
+        assert_eq!(owasp_identifier["name"], "A09:2021");
+        assert_eq!(owasp_identifier["value"], "A09:2021");
+    }
 }
@@ -0,0 +1,65 @@
+use std::sync::OnceLock;
+
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Style, Theme, ThemeSet};
+use syntect::html::{styled_line_to_highlighted_html, IncludeBackground};
+use syntect::parsing::{SyntaxReference, SyntaxSet};
+use syntect::util::{as_24_bit_terminal_escaped, LinesWithEndings};
+
+use crate::enums::Language;
+
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAX_SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn theme() -> &'static Theme {
+    static THEME_SET: OnceLock<ThemeSet> = OnceLock::new();
+    &THEME_SET.get_or_init(ThemeSet::load_defaults).themes["base16-ocean.dark"]
+}
+
+fn syntax_for(language: &Language) -> Option<&'static SyntaxReference> {
+    let extension = match language {
+        Language::CSharp => "cs",
+        Language::GraphQL => "graphql",
+        Language::Java => "java",
+        Language::Kotlin => "kt",
+        Language::Python => "py",
+        Language::Ruby => "rb",
+        Language::SQL => "sql",
+        Language::Typescript => "ts",
+    };
+    syntax_set().find_syntax_by_extension(extension)
+}
+
+/// Highlight `code` for `language` into 24-bit ANSI-colored lines, one per input line, for the
+/// console's code-frame gutter. Falls back to the unmodified lines when `language` has no
+/// matching `syntect` syntax definition.
+pub fn highlight_console_lines(code: &str, language: &Language) -> Vec<String> {
+    let Some(syntax) = syntax_for(language) else {
+        return code.lines().map(str::to_string).collect();
+    };
+    let mut highlighter = HighlightLines::new(syntax, theme());
+    LinesWithEndings::from(code)
+        .map(|line| {
+            let ranges: Vec<(Style, &str)> =
+                highlighter.highlight_line(line, syntax_set()).unwrap_or_default();
+            as_24_bit_terminal_escaped(&ranges[..], false)
+        })
+        .collect()
+}
+
+/// Highlight `code` for `language` into an HTML block with inline styles, for Markdown reports
+/// rendered by GitHub. Returns `None` when `language` has no matching `syntect` syntax
+/// definition, so the caller can fall back to a plain fenced code block.
+pub fn highlight_markdown_html(code: &str, language: &Language) -> Option<String> {
+    let syntax = syntax_for(language)?;
+    let mut highlighter = HighlightLines::new(syntax, theme());
+    let mut html = String::from("<pre>\n");
+    for line in LinesWithEndings::from(code) {
+        let ranges: Vec<(Style, &str)> = highlighter.highlight_line(line, syntax_set()).ok()?;
+        html.push_str(&styled_line_to_highlighted_html(&ranges[..], IncludeBackground::Yes).ok()?);
+    }
+    html.push_str("</pre>");
+    Some(html)
+}
@@ -0,0 +1,191 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::enums::{Sensitivity, Severity};
+use crate::structs::{DataElementOccurrence, ScanResults, Vulnerability};
+
+#[derive(Serialize)]
+pub struct LspJson {
+    diagnostics: Vec<LspDiagnostic>,
+}
+
+#[derive(Serialize)]
+struct LspDiagnostic {
+    range: LspRange,
+    severity: String,
+    code: String,
+    message: String,
+}
+
+/// Line and column are 1-based, matching every other position the scanner reports (SARIF regions,
+/// `url_link` anchors, etc.) rather than LSP's native 0-based `Position`; editors wiring this up
+/// need to subtract one from each before handing it to `textDocument/publishDiagnostics`.
+#[derive(Serialize)]
+struct LspRange {
+    path: String,
+    start: LspPosition,
+    end: LspPosition,
+}
+
+#[derive(Serialize)]
+struct LspPosition {
+    line: usize,
+    column: usize,
+}
+
+fn severity_to_lsp(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "error",
+        Severity::Medium => "warning",
+        Severity::Low => "information",
+    }
+}
+
+fn sensitivity_to_lsp(sensitivity: &Sensitivity) -> &'static str {
+    match sensitivity {
+        Sensitivity::Critical => "error",
+        Sensitivity::Medium => "warning",
+        Sensitivity::Low => "information",
+    }
+}
+
+fn diagnostic_for_vulnerability(vulnerability: &Vulnerability) -> LspDiagnostic {
+    LspDiagnostic {
+        range: LspRange {
+            path: vulnerability.relative_file_path.clone(),
+            start: LspPosition {
+                line: vulnerability.line_start,
+                column: vulnerability.column_start,
+            },
+            end: LspPosition { line: vulnerability.line_end, column: vulnerability.column_end },
+        },
+        severity: severity_to_lsp(&vulnerability.severity).to_string(),
+        code: vulnerability.hash.clone(),
+        message: format!(
+            "{} ({})",
+            vulnerability.description,
+            vulnerability.data_element_names.join(", ")
+        ),
+    }
+}
+
+fn diagnostic_for_occurrence(occurrence: &DataElementOccurrence) -> LspDiagnostic {
+    LspDiagnostic {
+        range: LspRange {
+            path: occurrence.relative_file_path.clone(),
+            start: LspPosition { line: occurrence.line_start, column: occurrence.column_start },
+            end: LspPosition { line: occurrence.line_end, column: occurrence.column_end },
+        },
+        severity: sensitivity_to_lsp(&occurrence.sensitivity).to_string(),
+        code: occurrence.hash.clone(),
+        message: format!("Sensitive data element '{}' found here.", occurrence.data_element_name),
+    }
+}
+
+fn build_lsp_json(results: &ScanResults) -> LspJson {
+    let mut diagnostics: Vec<LspDiagnostic> =
+        results.vulnerabilities.iter().map(diagnostic_for_vulnerability).collect();
+    diagnostics.extend(results.occurrences.iter().map(diagnostic_for_occurrence));
+    LspJson { diagnostics }
+}
+
+pub fn generate_lsp_output(results: &ScanResults) -> Result<LspJson> {
+    println!("Generating LSP diagnostics file ...");
+    let now = chrono::offset::Local::now();
+    let file_path = match &results.output_filename {
+        Some(path) => &results.repository.path.join(path),
+        None => &results
+            .repository
+            .path
+            .join(now.format("hounddog-%Y-%m-%d-%H-%M-%S.lsp.json").to_string()),
+    };
+
+    let lsp_json = build_lsp_json(results);
+    serde_json::to_writer_pretty(std::fs::File::create(file_path)?, &lsp_json)?;
+    println!("file://{}", file_path.display());
+    Ok(lsp_json)
+}
+
+/// Print the diagnostics for `results` straight to stdout as a single JSON line, for `--file`
+/// mode's incremental editor integration where a file on disk would only add latency.
+pub fn print_lsp_output(results: &ScanResults) -> Result<()> {
+    let lsp_json = build_lsp_json(results);
+    println!("{}", serde_json::to_string(&lsp_json)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::common::test_fixtures::{test_config, test_occurrence, test_vulnerability};
+
+    #[test]
+    fn emits_diagnostics_for_vulnerabilities_and_occurrences() {
+        let config = test_config(crate::enums::OutputFormat::LspJson);
+        let results =
+            ScanResults::new(&config, vec![test_vulnerability()], vec![test_occurrence()]);
+
+        let lsp_json = generate_lsp_output(&results).unwrap();
+        let value = serde_json::to_value(&lsp_json).unwrap();
+        let diagnostics = value["diagnostics"].as_array().unwrap();
+
+        assert_eq!(diagnostics.len(), 2);
+        let vulnerability_diagnostic =
+            diagnostics.iter().find(|d| d["code"] == "deadbeef").unwrap();
+        assert_eq!(vulnerability_diagnostic["severity"], "error");
+        assert_eq!(vulnerability_diagnostic["range"]["path"], "app.py");
+        assert_eq!(vulnerability_diagnostic["range"]["start"]["line"], 10);
+
+        let occurrence_diagnostic = diagnostics.iter().find(|d| d["code"] == "cafebabe").unwrap();
+        assert_eq!(occurrence_diagnostic["severity"], "error");
+        assert_eq!(occurrence_diagnostic["range"]["start"]["line"], 5);
+    }
+
+    #[test]
+    fn emits_diagnostics_for_graphql_findings() {
+        use crate::enums::{Language, Sensitivity, Source};
+        use crate::structs::{DataElement, GraphQLFinding};
+
+        let mut config = test_config(crate::enums::OutputFormat::LspJson);
+        config.data_elements.insert(
+            "email".to_string(),
+            DataElement {
+                id: "email".to_string(),
+                name: "Email".to_string(),
+                description: String::new(),
+                include_patterns: vec![],
+                exclude_patterns: vec![],
+                is_enabled: true,
+                sensitivity: Sensitivity::Critical,
+                source: Source::HoundDog,
+                tags: vec![],
+                validator: None,
+            },
+        );
+
+        let finding = GraphQLFinding {
+            data_element_ids: vec!["email".to_string()],
+            type_name: "User".to_string(),
+            field_name: "email".to_string(),
+            categories: vec!["Email".to_string()],
+            hash: "gqlhash".to_string(),
+            language: Language::GraphQL,
+            code_segment: "email: String!".to_string(),
+            relative_file_path: "schema.graphql".to_string(),
+            absolute_file_path: "/repo/schema.graphql".to_string(),
+            line: 3,
+            column: 3,
+            deprecated: false,
+        };
+
+        let results = ScanResults::new(&config, vec![], finding.to_occurrences(&config));
+        let lsp_json = generate_lsp_output(&results).unwrap();
+        let value = serde_json::to_value(&lsp_json).unwrap();
+        let diagnostics = value["diagnostics"].as_array().unwrap();
+
+        let graphql_diagnostic = diagnostics.iter().find(|d| d["code"] == "gqlhash").unwrap();
+        assert_eq!(graphql_diagnostic["range"]["path"], "schema.graphql");
+        assert_eq!(graphql_diagnostic["range"]["start"]["line"], 3);
+        assert_eq!(graphql_diagnostic["message"], "Sensitive data element 'Email' found here.");
+    }
+}
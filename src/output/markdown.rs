@@ -4,9 +4,31 @@ use anyhow::Result;
 use indexmap::IndexMap;
 
 use crate::{markdown_label, markdown_note, markdown_url};
-use crate::enums::Severity;
+use crate::enums::{Language, Severity};
+use crate::output::highlight::highlight_markdown_html;
 use crate::structs::ScanResults;
 
+/// Write `code` as a fenced ```` ```{language} ```` block, or — when `highlight` is set and
+/// `language` has a matching `syntect` syntax definition — as a highlighted HTML block instead,
+/// so GitHub-rendered reports show colored code.
+fn write_code_block(
+    markdown: &mut std::fs::File,
+    code: &str,
+    language: &Language,
+    highlight: bool,
+) -> Result<()> {
+    if highlight {
+        if let Some(html) = highlight_markdown_html(code, language) {
+            writeln!(markdown, "{}", html)?;
+            return Ok(());
+        }
+    }
+    writeln!(markdown, "```{}", language)?;
+    writeln!(markdown, "{}", code)?;
+    writeln!(markdown, "```")?;
+    Ok(())
+}
+
 pub fn generate_markdown_output(results: &ScanResults) -> Result<()> {
     println!("Saving Markdown output:");
     let now = chrono::offset::Local::now();
@@ -75,9 +97,12 @@ pub fn generate_markdown_output(results: &ScanResults) -> Result<()> {
             }
 
             // Code segment and remediation
-            writeln!(markdown, "```{}", v.language)?;
-            writeln!(markdown, "{}", v.code_segment)?;
-            writeln!(markdown, "```")?;
+            write_code_block(
+                &mut markdown,
+                &v.code_segment,
+                &v.language,
+                results.markdown_syntax_highlighting,
+            )?;
             writeln!(markdown, "{}", sink.remediation)?;
 
             // More details
@@ -168,9 +193,12 @@ pub fn generate_markdown_output(results: &ScanResults) -> Result<()> {
                         markdown_url!(format!("{}:{}", o.absolute_file_path, o.line_start))
                     )?,
                 }
-                writeln!(markdown, "```{}", o.language)?;
-                writeln!(markdown, "{}", o.code_segment)?;
-                writeln!(markdown, "```")?;
+                write_code_block(
+                    &mut markdown,
+                    &o.code_segment,
+                    &o.language,
+                    results.markdown_syntax_highlighting,
+                )?;
             }
             let ignore_instruction = markdown_note!(
                 "To ignore this data element, use flag `--skip-data-element={}`\n",
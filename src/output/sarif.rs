@@ -1,10 +1,410 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
 use anyhow::Result;
+use serde::Serialize;
+
+use crate::enums::Severity;
+use crate::structs::{ScanResults, Vulnerability};
+
+const SARIF_SCHEMA: &str =
+    "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json";
+const DRIVER_NAME: &str = "HoundDog.ai";
+const DRIVER_INFORMATION_URI: &str = "https://hounddog.ai";
+const FINGERPRINT_KEY: &str = "houndDogFindingHash/v1";
+
+#[derive(Serialize)]
+pub struct Sarif {
+    #[serde(rename = "$schema")]
+    schema: String,
+    version: String,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Serialize)]
+struct SarifRun {
+    tool: SarifTool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Serialize)]
+struct SarifDriver {
+    name: String,
+    #[serde(rename = "informationUri")]
+    information_uri: String,
+    version: String,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Serialize)]
+struct SarifRule {
+    id: String,
+    name: String,
+    #[serde(rename = "shortDescription")]
+    short_description: SarifMessage,
+    #[serde(rename = "fullDescription")]
+    full_description: SarifMessage,
+    help: SarifMessage,
+    #[serde(rename = "helpUri")]
+    help_uri: String,
+    #[serde(rename = "defaultConfiguration")]
+    default_configuration: SarifRuleConfiguration,
+    properties: SarifRuleProperties,
+}
+
+#[derive(Serialize)]
+struct SarifRuleConfiguration {
+    level: String,
+}
+
+#[derive(Serialize)]
+struct SarifRuleProperties {
+    cwe: Vec<String>,
+    owasp: Vec<String>,
+    tags: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct SarifResult {
+    #[serde(rename = "ruleId")]
+    rule_id: String,
+    #[serde(rename = "ruleIndex")]
+    rule_index: usize,
+    level: String,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    #[serde(rename = "partialFingerprints")]
+    partial_fingerprints: SarifFingerprints,
+    #[serde(rename = "codeFlows", skip_serializing_if = "Vec::is_empty")]
+    code_flows: Vec<SarifCodeFlow>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suppressions: Vec<SarifSuppression>,
+}
+
+/// Marks a result as previously acknowledged rather than newly introduced, per the SARIF
+/// `suppressions` property. `kind: "external"` is the correct value for a suppression recorded
+/// outside the analysis tool itself (here, a baseline file from a prior run).
+#[derive(Serialize)]
+struct SarifSuppression {
+    kind: String,
+}
+
+#[derive(Serialize)]
+struct SarifFingerprints {
+    #[serde(rename = "houndDogFindingHash/v1")]
+    hounddog_finding_hash: String,
+}
+
+#[derive(Serialize)]
+struct SarifCodeFlow {
+    #[serde(rename = "threadFlows")]
+    thread_flows: Vec<SarifThreadFlow>,
+}
+
+#[derive(Serialize)]
+struct SarifThreadFlow {
+    locations: Vec<SarifThreadFlowLocation>,
+}
 
-use crate::structs::ScanResults;
+#[derive(Serialize)]
+struct SarifThreadFlowLocation {
+    location: SarifLocation,
+}
 
-pub struct Sarif;
+#[derive(Serialize)]
+struct SarifLocation {
+    #[serde(rename = "physicalLocation")]
+    physical_location: SarifPhysicalLocation,
+}
 
-pub fn generate_sarif_output(results: &ScanResults) -> Result<Sarif> {
+#[derive(Serialize)]
+struct SarifPhysicalLocation {
+    #[serde(rename = "artifactLocation")]
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+struct SarifRegion {
+    #[serde(rename = "startLine")]
+    start_line: usize,
+    #[serde(rename = "endLine")]
+    end_line: usize,
+    #[serde(rename = "startColumn")]
+    start_column: usize,
+    #[serde(rename = "endColumn")]
+    end_column: usize,
+}
+
+#[derive(Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+impl SarifMessage {
+    fn new(text: impl Into<String>) -> SarifMessage {
+        SarifMessage { text: text.into() }
+    }
+}
+
+fn severity_to_level(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "error",
+        Severity::Medium => "warning",
+        Severity::Low => "note",
+    }
+}
+
+fn location_of(
+    relative_file_path: &str,
+    line_start: usize,
+    line_end: usize,
+    column_start: usize,
+    column_end: usize,
+) -> SarifLocation {
+    SarifLocation {
+        physical_location: SarifPhysicalLocation {
+            artifact_location: SarifArtifactLocation { uri: relative_file_path.to_string() },
+            region: SarifRegion {
+                start_line: line_start,
+                end_line: line_end,
+                start_column: column_start,
+                end_column: column_end,
+            },
+        },
+    }
+}
+
+/// The taint path for a vulnerability: every occurrence of the data elements it flags in the same
+/// file at or before the sink line, in the order they were found, followed by the sink location
+/// itself so reviewers see source through sink rather than just the final line.
+fn code_flow_for(results: &ScanResults, vulnerability: &Vulnerability) -> SarifCodeFlow {
+    let mut locations: Vec<SarifThreadFlowLocation> = results
+        .occurrences
+        .iter()
+        .filter(|occurrence| {
+            vulnerability.data_element_ids.contains(&occurrence.data_element_id)
+                && occurrence.relative_file_path == vulnerability.relative_file_path
+                && occurrence.line_start <= vulnerability.line_start
+        })
+        .map(|occurrence| SarifThreadFlowLocation {
+            location: location_of(
+                &occurrence.relative_file_path,
+                occurrence.line_start,
+                occurrence.line_end,
+                occurrence.column_start,
+                occurrence.column_end,
+            ),
+        })
+        .collect();
+    locations.push(SarifThreadFlowLocation {
+        location: location_of(
+            &vulnerability.relative_file_path,
+            vulnerability.line_start,
+            vulnerability.line_end,
+            vulnerability.column_start,
+            vulnerability.column_end,
+        ),
+    });
+    SarifCodeFlow { thread_flows: vec![SarifThreadFlow { locations }] }
+}
+
+fn rules(results: &ScanResults) -> Vec<SarifRule> {
+    let mut rules: Vec<SarifRule> = results
+        .data_sinks
+        .values()
+        .flat_map(|map| map.values())
+        .map(|data_sink| SarifRule {
+            id: data_sink.id.clone(),
+            name: data_sink.name.clone(),
+            short_description: SarifMessage::new(data_sink.description.clone()),
+            full_description: SarifMessage::new(data_sink.description.clone()),
+            help: SarifMessage::new(data_sink.remediation.clone()),
+            help_uri: format!("{DRIVER_INFORMATION_URI}/rules/{}", data_sink.id),
+            // Actual severity is derived per-finding from the sensitivity of the data elements
+            // involved (see `Vulnerability::severity`), so there's no single intrinsic severity
+            // for a sink; "warning" is a reasonable default for tooling that only reads the rule.
+            default_configuration: SarifRuleConfiguration { level: "warning".to_string() },
+            properties: SarifRuleProperties {
+                tags: data_sink.cwe.iter().chain(data_sink.owasp.iter()).cloned().collect(),
+                cwe: data_sink.cwe.clone(),
+                owasp: data_sink.owasp.clone(),
+            },
+        })
+        .collect();
+    rules.sort_by(|a, b| a.id.cmp(&b.id));
+    rules
+}
+
+/// Load the set of finding fingerprints to suppress from `path`, which may be either a plain JSON
+/// array of hash strings or a SARIF file previously emitted by `generate_sarif_output` (in which
+/// case the fingerprints are pulled back out of each result's `partialFingerprints`). A missing
+/// file is treated as an empty baseline so the first scan doesn't fail.
+pub fn load_sarif_baseline(path: &Path) -> Result<HashSet<String>> {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return Ok(HashSet::new());
+    };
+    let value: serde_json::Value = serde_json::from_str(&contents)?;
+
+    if let Some(hashes) = value.as_array() {
+        return Ok(hashes.iter().filter_map(|hash| hash.as_str()).map(str::to_string).collect());
+    }
+
+    Ok(value["runs"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .flat_map(|run| run["results"].as_array().into_iter().flatten())
+        .filter_map(|result| result["partialFingerprints"][FINGERPRINT_KEY].as_str())
+        .map(str::to_string)
+        .collect())
+}
+
+pub fn generate_sarif_output(
+    results: &ScanResults,
+    suppressed_hashes: &HashSet<String>,
+) -> Result<Sarif> {
     println!("Generating SARIF output ...");
-    Ok(Sarif)
+    let now = chrono::offset::Local::now();
+    let file_path = match &results.output_filename {
+        Some(path) => &results.repository.path.join(path),
+        None => &results
+            .repository
+            .path
+            .join(now.format("hounddog-%Y-%m-%d-%H-%M-%S.sarif.json").to_string()),
+    };
+
+    let rules = rules(results);
+    let rule_indices: HashMap<&str, usize> =
+        rules.iter().enumerate().map(|(index, rule)| (rule.id.as_str(), index)).collect();
+
+    let sarif = Sarif {
+        schema: SARIF_SCHEMA.to_string(),
+        version: "2.1.0".to_string(),
+        runs: vec![SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: DRIVER_NAME.to_string(),
+                    information_uri: DRIVER_INFORMATION_URI.to_string(),
+                    version: "1.0.0".to_string(),
+                    rules,
+                },
+            },
+            results: results
+                .vulnerabilities
+                .iter()
+                .map(|vulnerability| SarifResult {
+                    rule_id: vulnerability.data_sink_id.clone(),
+                    rule_index: rule_indices
+                        .get(vulnerability.data_sink_id.as_str())
+                        .copied()
+                        .unwrap_or(0),
+                    level: severity_to_level(&vulnerability.severity).to_string(),
+                    message: SarifMessage::new(format!(
+                        "{} Data elements: {}.",
+                        vulnerability.description,
+                        vulnerability.data_element_names.join(", ")
+                    )),
+                    locations: vec![location_of(
+                        &vulnerability.relative_file_path,
+                        vulnerability.line_start,
+                        vulnerability.line_end,
+                        vulnerability.column_start,
+                        vulnerability.column_end,
+                    )],
+                    partial_fingerprints: SarifFingerprints {
+                        hounddog_finding_hash: vulnerability.hash.clone(),
+                    },
+                    code_flows: vec![code_flow_for(results, vulnerability)],
+                    suppressions: if suppressed_hashes.contains(&vulnerability.hash) {
+                        vec![SarifSuppression { kind: "external".to_string() }]
+                    } else {
+                        vec![]
+                    },
+                })
+                .collect(),
+        }],
+    };
+
+    serde_json::to_writer_pretty(std::fs::File::create(file_path)?, &sarif)?;
+    println!("file://{}", file_path.display());
+    Ok(sarif)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::output::common::test_fixtures::{test_config, test_vulnerability};
+
+    #[test]
+    fn marks_baseline_hashes_as_suppressed() {
+        let config = test_config(crate::enums::OutputFormat::Sarif);
+        let results = ScanResults::new(&config, vec![test_vulnerability()], vec![]);
+        let suppressed_hashes: HashSet<String> = ["deadbeef".to_string()].into_iter().collect();
+
+        let sarif = generate_sarif_output(&results, &suppressed_hashes).unwrap();
+        let value = serde_json::to_value(&sarif).unwrap();
+
+        let result = &value["runs"][0]["results"][0];
+        assert_eq!(result["suppressions"][0]["kind"], "external");
+    }
+
+    #[test]
+    fn leaves_new_findings_unsuppressed() {
+        let config = test_config(crate::enums::OutputFormat::Sarif);
+        let results = ScanResults::new(&config, vec![test_vulnerability()], vec![]);
+
+        let sarif = generate_sarif_output(&results, &HashSet::new()).unwrap();
+        let value = serde_json::to_value(&sarif).unwrap();
+
+        let result = &value["runs"][0]["results"][0];
+        assert!(result.get("suppressions").is_none());
+    }
+
+    #[test]
+    fn loads_baseline_from_plain_hash_list() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("sarif_baseline_test_hashes.json");
+        std::fs::write(&path, r#"["deadbeef", "cafef00d"]"#).unwrap();
+
+        let baseline = load_sarif_baseline(&path).unwrap();
+
+        assert!(baseline.contains("deadbeef"));
+        assert!(baseline.contains("cafef00d"));
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn loads_baseline_from_previous_sarif_file() {
+        let config = test_config(crate::enums::OutputFormat::Sarif);
+        let results = ScanResults::new(&config, vec![test_vulnerability()], vec![]);
+        let sarif = generate_sarif_output(&results, &HashSet::new()).unwrap();
+
+        let dir = std::env::temp_dir();
+        let path = dir.join("sarif_baseline_test_prior_run.json");
+        serde_json::to_writer(std::fs::File::create(&path).unwrap(), &sarif).unwrap();
+
+        let baseline = load_sarif_baseline(&path).unwrap();
+
+        assert!(baseline.contains("deadbeef"));
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn missing_baseline_file_is_treated_as_empty() {
+        let baseline = load_sarif_baseline(Path::new("/nonexistent/baseline.json")).unwrap();
+        assert!(baseline.is_empty());
+    }
 }
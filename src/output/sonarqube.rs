@@ -0,0 +1,116 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::enums::Severity;
+use crate::structs::{ScanResults, Vulnerability};
+
+#[derive(Serialize)]
+pub struct SonarQubeJson {
+    issues: Vec<SonarQubeIssue>,
+}
+
+#[derive(Serialize)]
+struct SonarQubeIssue {
+    #[serde(rename = "engineId")]
+    engine_id: String,
+    #[serde(rename = "ruleId")]
+    rule_id: String,
+    severity: String,
+    #[serde(rename = "type")]
+    issue_type: String,
+    #[serde(rename = "primaryLocation")]
+    primary_location: SonarQubePrimaryLocation,
+}
+
+#[derive(Serialize)]
+struct SonarQubePrimaryLocation {
+    #[serde(rename = "filePath")]
+    file_path: String,
+    message: String,
+    #[serde(rename = "textRange")]
+    text_range: SonarQubeTextRange,
+}
+
+#[derive(Serialize)]
+struct SonarQubeTextRange {
+    #[serde(rename = "startLine")]
+    start_line: usize,
+    #[serde(rename = "endLine")]
+    end_line: usize,
+    #[serde(rename = "startColumn")]
+    start_column: usize,
+    #[serde(rename = "endColumn")]
+    end_column: usize,
+}
+
+/// SonarQube's generic issue import format only recognizes `BLOCKER`/`CRITICAL`/`MAJOR`/`MINOR`/
+/// `INFO`, so `Severity` collapses onto the closest match.
+fn severity_to_sonarqube(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "CRITICAL",
+        Severity::Medium => "MAJOR",
+        Severity::Low => "MINOR",
+    }
+}
+
+fn issue_for(vulnerability: &Vulnerability) -> SonarQubeIssue {
+    SonarQubeIssue {
+        engine_id: "hounddog".to_string(),
+        rule_id: vulnerability.data_sink_id.clone(),
+        severity: severity_to_sonarqube(&vulnerability.severity).to_string(),
+        issue_type: "VULNERABILITY".to_string(),
+        primary_location: SonarQubePrimaryLocation {
+            file_path: vulnerability.relative_file_path.clone(),
+            message: vulnerability.description.clone(),
+            text_range: SonarQubeTextRange {
+                start_line: vulnerability.line_start,
+                end_line: vulnerability.line_end,
+                start_column: vulnerability.column_start,
+                end_column: vulnerability.column_end,
+            },
+        },
+    }
+}
+
+pub fn generate_sonarqube_output(results: &ScanResults) -> Result<SonarQubeJson> {
+    println!("Generating SonarQube JSON file ...");
+    let now = chrono::offset::Local::now();
+    let file_path = match &results.output_filename {
+        Some(path) => &results.repository.path.join(path),
+        None => &results
+            .repository
+            .path
+            .join(now.format("hounddog-%Y-%m-%d-%H-%M-%S.sonarqube.json").to_string()),
+    };
+
+    let sonarqube_json = SonarQubeJson {
+        issues: results.vulnerabilities.iter().map(issue_for).collect(),
+    };
+
+    serde_json::to_writer_pretty(std::fs::File::create(file_path)?, &sonarqube_json)?;
+    println!("file://{}", file_path.display());
+    Ok(sonarqube_json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::common::test_fixtures::{test_config, test_vulnerability};
+
+    #[test]
+    fn emits_the_sonarqube_generic_issue_shape() {
+        let config = test_config(crate::enums::OutputFormat::SonarQube);
+        let results = ScanResults::new(&config, vec![test_vulnerability()], vec![]);
+
+        let sonarqube_json = generate_sonarqube_output(&results).unwrap();
+        let value = serde_json::to_value(&sonarqube_json).unwrap();
+
+        let issue = &value["issues"][0];
+        assert_eq!(issue["engineId"], "hounddog");
+        assert_eq!(issue["ruleId"], "log-sensitive-data");
+        assert_eq!(issue["severity"], "CRITICAL");
+        assert_eq!(issue["type"], "VULNERABILITY");
+        assert_eq!(issue["primaryLocation"]["filePath"], "app.py");
+        assert_eq!(issue["primaryLocation"]["textRange"]["startLine"], 10);
+    }
+}
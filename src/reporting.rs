@@ -0,0 +1,299 @@
+use std::env;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::Result;
+use colored::Colorize;
+use reqwest::blocking::{Client as HttpClient, RequestBuilder};
+use reqwest::header::{HeaderMap, ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use serde_json::{json, Value};
+
+use crate::enums::{GitProvider, Severity};
+use crate::output::common::get_vulnerability_summary;
+use crate::print_warn;
+use crate::structs::{ScanResults, VulnerabilitySummary, Vulnerability};
+
+const MAX_ATTEMPTS: u32 = 3;
+
+/// Where to post findings back to: the repo/PR identifiers plus an auth token. Built entirely
+/// from CI environment variables, since that's the only place a reviewable PR/MR number and a
+/// scoped token can come from. `None` means there's no token or no open PR/MR for this run, in
+/// which case reporting is skipped rather than failing the scan.
+struct ReportingContext {
+    provider: GitProvider,
+    token: String,
+    owner_and_repo: String,
+    pr_number: String,
+    api_base: String,
+}
+
+impl ReportingContext {
+    fn from_env(provider: &GitProvider, base_url: &str) -> Option<ReportingContext> {
+        let owner_and_repo = owner_and_repo_path(base_url);
+        match provider {
+            GitProvider::GitHub => Some(ReportingContext {
+                provider: GitProvider::GitHub,
+                token: env::var("GITHUB_TOKEN").ok()?,
+                owner_and_repo,
+                pr_number: env::var("GITHUB_REF")
+                    .ok()
+                    .and_then(|r| r.strip_prefix("refs/pull/").map(str::to_string))
+                    .and_then(|r| r.strip_suffix("/merge").map(str::to_string))?,
+                api_base: "https://api.github.com".to_string(),
+            }),
+            GitProvider::GitLab => Some(ReportingContext {
+                provider: GitProvider::GitLab,
+                token: env::var("CI_JOB_TOKEN").ok()?,
+                owner_and_repo,
+                pr_number: env::var("CI_MERGE_REQUEST_IID").ok()?,
+                api_base: env::var("CI_API_V4_URL")
+                    .unwrap_or_else(|_| "https://gitlab.com/api/v4".to_string()),
+            }),
+            GitProvider::Bitbucket => Some(ReportingContext {
+                provider: GitProvider::Bitbucket,
+                token: env::var("BITBUCKET_TOKEN").ok()?,
+                owner_and_repo,
+                pr_number: env::var("BITBUCKET_PR_ID").ok()?,
+                api_base: "https://api.bitbucket.org/2.0".to_string(),
+            }),
+        }
+    }
+}
+
+/// "https://github.com/org/repo" -> "org/repo". Works the same way for GitLab and Bitbucket
+/// remote URLs, since `base_url` is already normalized to a bare `scheme://domain/path`.
+fn owner_and_repo_path(base_url: &str) -> String {
+    base_url.splitn(4, '/').nth(3).unwrap_or_default().to_string()
+}
+
+fn build_http_client(token: &str) -> Result<HttpClient> {
+    let mut headers = HeaderMap::new();
+    headers.insert(AUTHORIZATION, format!("Bearer {}", token).parse()?);
+    headers.insert(ACCEPT, "application/json".parse()?);
+    headers.insert(CONTENT_TYPE, "application/json".parse()?);
+    Ok(HttpClient::builder()
+        .default_headers(headers)
+        .timeout(Duration::from_secs(10))
+        .user_agent("hounddog-scanner")
+        .build()?)
+}
+
+/// Send a JSON `body` built by `request`, retrying transient failures (connection errors and
+/// 429/5xx responses) with exponential backoff. Gives up after [`MAX_ATTEMPTS`] and returns the
+/// last error.
+fn send_with_retry(request: impl Fn() -> (RequestBuilder, Value)) -> Result<()> {
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        let (builder, body) = request();
+        match builder.body(serde_json::to_string(&body)?).send() {
+            Ok(response) if response.status().is_success() => return Ok(()),
+            Ok(response) if response.status().is_server_error()
+                || response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS =>
+            {
+                if attempt >= MAX_ATTEMPTS {
+                    return Err(anyhow::anyhow!(
+                        "Git provider API returned {} after {} attempts",
+                        response.status(),
+                        attempt
+                    ));
+                }
+            }
+            Ok(response) => {
+                return Err(anyhow::anyhow!(
+                    "Git provider API returned {}: {}",
+                    response.status(),
+                    response.text().unwrap_or_default()
+                ));
+            }
+            Err(e) if attempt < MAX_ATTEMPTS => {
+                print_warn!("Git provider request failed (attempt {}): {}", attempt, e);
+            }
+            Err(e) => return Err(e.into()),
+        }
+        thread::sleep(Duration::from_millis(500 * 2u64.pow(attempt - 1)));
+    }
+}
+
+/// Push `results` back to the code host as inline PR/MR review comments, plus a check run
+/// (GitHub) or code-insights report (Bitbucket) summarizing the overall counts; GitLab gets only
+/// the MR discussions. Only vulnerabilities whose file is in `changed_files` are
+/// commented on when a baseline diff is available, so untouched code isn't spammed; when
+/// `changed_files` is `None` (no `--diff-baseline`), every finding is eligible.
+pub fn report_scan_results(results: &ScanResults, changed_files: Option<&[String]>) -> Result<()> {
+    let Some(provider) = &results.repository.git_provider else {
+        print_warn!("Could not detect Git provider; skipping inline review comments.");
+        return Ok(());
+    };
+    let Some(ctx) = ReportingContext::from_env(provider, &results.repository.base_url) else {
+        print_warn!("No Git provider token or PR/MR context found; skipping inline review comments.");
+        return Ok(());
+    };
+
+    let http = build_http_client(&ctx.token)?;
+    let summary = get_vulnerability_summary(&results.vulnerabilities);
+    let in_scope: Vec<&Vulnerability> = results
+        .vulnerabilities
+        .iter()
+        .filter(|v| changed_files.map_or(true, |files| files.contains(&v.relative_file_path)))
+        .collect();
+
+    match &ctx.provider {
+        GitProvider::GitHub => report_github(&http, &ctx, results, &summary, &in_scope),
+        GitProvider::GitLab => report_gitlab(&http, &ctx, results, &summary, &in_scope),
+        GitProvider::Bitbucket => report_bitbucket(&http, &ctx, results, &summary, &in_scope),
+    }
+}
+
+fn report_github(
+    http: &HttpClient,
+    ctx: &ReportingContext,
+    results: &ScanResults,
+    summary: &VulnerabilitySummary,
+    vulnerabilities: &[&Vulnerability],
+) -> Result<()> {
+    let commit = &results.repository.commit;
+
+    send_with_retry(|| {
+        let request = http.post(format!("{}/repos/{}/check-runs", ctx.api_base, ctx.owner_and_repo));
+        let body = json!({
+            "name": "HoundDog.ai Sensitive Data Scan",
+            "head_sha": commit,
+            "status": "completed",
+            "conclusion": if summary.total > 0 { "neutral" } else { "success" },
+            "output": {
+                "title": format!("{} sensitive data findings", summary.total),
+                "summary": format!(
+                    "Critical: {}\nMedium: {}\nLow: {}",
+                    summary.critical, summary.medium, summary.low
+                ),
+            },
+        });
+        (request, body)
+    })?;
+
+    for vulnerability in vulnerabilities {
+        send_with_retry(|| {
+            let request = http.post(format!(
+                "{}/repos/{}/pulls/{}/comments",
+                ctx.api_base, ctx.owner_and_repo, ctx.pr_number
+            ));
+            let body = json!({
+                "commit_id": commit,
+                "path": vulnerability.relative_file_path,
+                "line": vulnerability.line_end,
+                "side": "RIGHT",
+                "body": review_comment_body(vulnerability),
+            });
+            (request, body)
+        })?;
+    }
+    Ok(())
+}
+
+fn report_gitlab(
+    http: &HttpClient,
+    ctx: &ReportingContext,
+    results: &ScanResults,
+    _summary: &VulnerabilitySummary,
+    vulnerabilities: &[&Vulnerability],
+) -> Result<()> {
+    let commit = &results.repository.commit;
+    let project = urlencoding_path(&ctx.owner_and_repo);
+
+    for vulnerability in vulnerabilities {
+        send_with_retry(|| {
+            let request = http.post(format!(
+                "{}/projects/{}/merge_requests/{}/discussions",
+                ctx.api_base, project, ctx.pr_number
+            ));
+            let body = json!({
+                "body": review_comment_body(vulnerability),
+                "position": {
+                    "position_type": "text",
+                    "base_sha": commit,
+                    "start_sha": commit,
+                    "head_sha": commit,
+                    "new_path": vulnerability.relative_file_path,
+                    "new_line": vulnerability.line_end,
+                },
+            });
+            (request, body)
+        })?;
+    }
+    Ok(())
+}
+
+fn report_bitbucket(
+    http: &HttpClient,
+    ctx: &ReportingContext,
+    results: &ScanResults,
+    summary: &VulnerabilitySummary,
+    vulnerabilities: &[&Vulnerability],
+) -> Result<()> {
+    let commit = &results.repository.commit;
+    let report_id = "hounddog-sensitive-data-scan";
+
+    send_with_retry(|| {
+        let request = http.put(format!(
+            "{}/repositories/{}/commit/{}/reports/{}",
+            ctx.api_base, ctx.owner_and_repo, commit, report_id
+        ));
+        let body = json!({
+            "title": "HoundDog.ai Sensitive Data Scan",
+            "report_type": "SECURITY",
+            "result": if summary.total > 0 { "FAILED" } else { "PASSED" },
+            "data": [
+                { "title": "Critical", "type": "NUMBER", "value": summary.critical },
+                { "title": "Medium", "type": "NUMBER", "value": summary.medium },
+                { "title": "Low", "type": "NUMBER", "value": summary.low },
+            ],
+        });
+        (request, body)
+    })?;
+
+    let annotations: Vec<_> = vulnerabilities
+        .iter()
+        .map(|vulnerability| {
+            json!({
+                "external_id": vulnerability.hash,
+                "path": vulnerability.relative_file_path,
+                "line": vulnerability.line_end,
+                "summary": vulnerability.description,
+                "annotation_type": "VULNERABILITY",
+                "severity": bitbucket_severity(vulnerability),
+            })
+        })
+        .collect();
+    if annotations.is_empty() {
+        return Ok(());
+    }
+    send_with_retry(|| {
+        let request = http.post(format!(
+            "{}/repositories/{}/commit/{}/reports/{}/annotations",
+            ctx.api_base, ctx.owner_and_repo, commit, report_id
+        ));
+        (request, json!(annotations))
+    })
+}
+
+fn bitbucket_severity(vulnerability: &Vulnerability) -> &'static str {
+    match &vulnerability.severity {
+        Severity::Critical => "CRITICAL",
+        Severity::Medium => "MEDIUM",
+        Severity::Low => "LOW",
+    }
+}
+
+fn review_comment_body(vulnerability: &Vulnerability) -> String {
+    format!(
+        "**HoundDog.ai**: {}\n\nData elements: {}\n\n[View finding]({})",
+        vulnerability.description,
+        vulnerability.data_element_names.join(", "),
+        vulnerability.url_link
+    )
+}
+
+fn urlencoding_path(path: &str) -> String {
+    path.replace('/', "%2F")
+}
@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use std::fs::{read_dir, read_to_string, File};
+use std::fs::{read_dir, read_to_string};
 use std::path::Path;
 
 use anyhow::Result;
@@ -8,6 +8,19 @@ use colored::Colorize;
 use crate::enums::Language;
 use crate::print_err;
 use crate::structs::{DataElement, DataSink, Sanitizer};
+use crate::utils::serde::deserialize_from_path;
+
+/// Rule-pack file extensions accepted alongside the default JSON, so a rule author can write
+/// data elements/sinks/sanitizers in whichever format they prefer.
+const RULE_FILE_EXTENSIONS: [&str; 5] = ["json", "yaml", "yml", "toml", "ron"];
+
+fn is_rule_file(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map_or(false, |ext| RULE_FILE_EXTENSIONS.contains(&ext))
+}
 
 pub fn get_local_data_elements(dir: &Path) -> Result<HashMap<String, DataElement>> {
     let data_elements_dir = dir.join("data-elements");
@@ -15,9 +28,8 @@ pub fn get_local_data_elements(dir: &Path) -> Result<HashMap<String, DataElement
     let mut data_elements = HashMap::new();
     for entry in read_dir(data_elements_dir)? {
         let path = entry?.path();
-        if path.is_file() && path.extension().map_or(false, |ext| ext == "json") {
-            let file = File::open(&path)?;
-            let data_element: DataElement = serde_json::from_reader(file)?;
+        if is_rule_file(&path) {
+            let data_element: DataElement = deserialize_from_path(&path)?;
             data_elements.insert(data_element.id.clone(), data_element);
         }
     }
@@ -27,12 +39,12 @@ pub fn get_local_data_elements(dir: &Path) -> Result<HashMap<String, DataElement
 pub fn get_local_data_sinks(dir: &Path) -> Result<HashMap<Language, HashMap<String, DataSink>>> {
     let data_sinks_dir = dir.join("data-sinks");
     let remediations_dir = dir.join("remediations");
-    
+
     let mut data_sinks: HashMap<Language, HashMap<String, DataSink>> = HashMap::new();
     for entry in read_dir(data_sinks_dir)? {
         let path = entry?.path();
-        if path.is_file() && path.extension().map_or(false, |ext| ext == "json") {
-            match serde_json::from_str::<DataSink>(&read_to_string(&path)?) {
+        if is_rule_file(&path) {
+            match deserialize_from_path::<DataSink>(&path) {
                 Ok(mut data_sink) => {
                     let remediation_path = remediations_dir.join(format!("{}.md", data_sink.id));
                     if remediation_path.exists() {
@@ -51,7 +63,13 @@ pub fn get_local_data_sinks(dir: &Path) -> Result<HashMap<Language, HashMap<Stri
 }
 
 pub fn get_local_sanitizers(dir: &Path) -> Result<Vec<Sanitizer>> {
-    let file = File::open(dir.join("sanitizers/sanitizers.json"))?;
-    let sanitizers: Vec<Sanitizer> = serde_json::from_reader(file)?;
-    Ok(sanitizers)
+    let sanitizers_dir = dir.join("sanitizers");
+    for ext in RULE_FILE_EXTENSIONS {
+        let path = sanitizers_dir.join(format!("sanitizers.{}", ext));
+        if path.is_file() {
+            return deserialize_from_path(&path);
+        }
+    }
+    // Fall back to the default JSON path so a missing file still surfaces the usual I/O error.
+    deserialize_from_path(&sanitizers_dir.join("sanitizers.json"))
 }
@@ -1,7 +1,245 @@
 use std::collections::VecDeque;
+use std::ops::Range;
 
 use tree_sitter::Node;
 
+/// A 1-based line and column position within a source buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LineCol {
+    pub line: usize,
+    pub col: usize,
+}
+
+/// Precomputed byte offsets of every `\n` in a source buffer, built once per file so that
+/// offset→line:column lookups and line slicing are O(log n) instead of rescanning the buffer
+/// for every finding (which is quadratic when many matches land in the same file).
+pub struct LineIndex {
+    newlines: Vec<usize>,
+    len: usize,
+}
+
+impl LineIndex {
+    pub fn new(source: &[u8]) -> LineIndex {
+        let newlines =
+            source.iter().enumerate().filter_map(|(i, &b)| (b == b'\n').then_some(i)).collect();
+        LineIndex { newlines, len: source.len() }
+    }
+
+    /// The 1-based line and column of a byte `offset`.
+    pub fn line_col(&self, offset: usize) -> LineCol {
+        let line = self.newlines.partition_point(|&nl| nl < offset);
+        let line_start = if line == 0 { 0 } else { self.newlines[line - 1] + 1 };
+        LineCol { line: line + 1, col: offset - line_start + 1 }
+    }
+
+    /// The byte range of the line containing `offset`, excluding the trailing newline.
+    pub fn line_range(&self, offset: usize) -> Range<usize> {
+        let line = self.newlines.partition_point(|&nl| nl < offset);
+        let start = if line == 0 { 0 } else { self.newlines[line - 1] + 1 };
+        let end = self.newlines.get(line).copied().unwrap_or(self.len);
+        start..end
+    }
+
+    /// The byte range of 1-based `line`, excluding its newline, or `None` past end of file.
+    pub fn range_of_line(&self, line: usize) -> Option<Range<usize>> {
+        if line == 0 {
+            return None;
+        }
+        let start = if line == 1 { 0 } else { self.newlines.get(line - 2).map(|&nl| nl + 1)? };
+        if start > self.len {
+            return None;
+        }
+        let end = self.newlines.get(line - 1).copied().unwrap_or(self.len);
+        Some(start..end)
+    }
+
+    /// The total number of lines in the source buffer.
+    pub fn line_count(&self) -> usize {
+        self.newlines.len() + 1
+    }
+
+    /// The byte ranges of every line overlapping `range`, each excluding its newline.
+    pub fn lines(&self, range: Range<usize>) -> impl Iterator<Item = Range<usize>> + '_ {
+        let first = self.newlines.partition_point(|&nl| nl < range.start);
+        let last = self.newlines.partition_point(|&nl| nl <= range.end);
+        (first..=last).map(move |line| {
+            let start = if line == 0 { 0 } else { self.newlines[line - 1] + 1 };
+            let end = self.newlines.get(line).copied().unwrap_or(self.len);
+            start..end
+        })
+    }
+}
+
+/// Decode a source string-literal token into its logical value, so data-element matchers run
+/// against the real string rather than its escaped encoding (e.g. `"foo\u{41}bar"` → `fooAbar`).
+///
+/// Recognizes raw strings (`r"..."`, `r#"..."#` with the `#` count honored), and decodes
+/// `\n`, `\t`, `\r`, `\0`, `\\`, `\"`, `\'`, `\xNN`, `\uXXXX`, and `\u{...}` escape sequences.
+/// Unknown escapes are left as the escaped character, matching how permissive the scanned
+/// languages are. Input that has no matching surrounding quotes (e.g. a prefixed `f"..."`) is
+/// trimmed and escape-decoded as a whole, with nothing stripped.
+pub fn unescape_string_literal(raw: &str) -> String {
+    let raw = raw.trim();
+
+    // Raw strings: an `r` prefix, then zero or more `#`, then the opening quote. The same
+    // number of `#` (and the quote) close the literal, and the body is taken verbatim.
+    if let Some(after_r) = raw.strip_prefix('r') {
+        let hashes = after_r.chars().take_while(|&c| c == '#').count();
+        let after_hashes = &after_r[hashes..];
+        if let Some(quote) = after_hashes.chars().next().filter(|c| *c == '"' || *c == '\'') {
+            let closing = format!("{}{}", quote, "#".repeat(hashes));
+            let body = &after_hashes[quote.len_utf8()..];
+            if let Some(end) = body.rfind(&closing) {
+                return body[..end].to_string();
+            }
+            return body.to_string();
+        }
+    }
+
+    let body = strip_quotes(raw);
+    decode_escapes(body)
+}
+
+/// Strip matching surrounding quotes, including triple quotes, from a string-literal body.
+fn strip_quotes(s: &str) -> &str {
+    for delimiter in ["\"\"\"", "'''", "\"", "'"] {
+        if s.len() >= 2 * delimiter.len()
+            && s.starts_with(delimiter)
+            && s.ends_with(delimiter)
+        {
+            return &s[delimiter.len()..s.len() - delimiter.len()];
+        }
+    }
+    s
+}
+
+/// Decode C-style escape sequences in an already-unquoted string body.
+fn decode_escapes(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some('0') => out.push('\0'),
+            Some('\\') => out.push('\\'),
+            Some('"') => out.push('"'),
+            Some('\'') => out.push('\''),
+            Some('x') => {
+                let hex: String = (0..2).filter_map(|_| chars.next()).collect();
+                match u8::from_str_radix(&hex, 16) {
+                    Ok(byte) => out.push(byte as char),
+                    Err(_) => out.push_str(&format!("\\x{}", hex)),
+                }
+            }
+            Some('u') => {
+                let code = if chars.peek() == Some(&'{') {
+                    chars.next();
+                    let hex: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                    u32::from_str_radix(&hex, 16).ok()
+                } else {
+                    let hex: String = (0..4).filter_map(|_| chars.next()).collect();
+                    u32::from_str_radix(&hex, 16).ok()
+                };
+                match code.and_then(char::from_u32) {
+                    Some(decoded) => out.push(decoded),
+                    None => out.push('\u{FFFD}'),
+                }
+            }
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// A single inline suppression directive parsed from a source comment.
+struct Suppression {
+    /// 1-based source line the suppression applies to.
+    line: usize,
+    /// Rule ids the directive is scoped to; `None` suppresses every detector on the line.
+    rules: Option<Vec<String>>,
+}
+
+/// Inline `hounddog:ignore` directives collected from a file's comments, letting authors
+/// suppress findings at the source instead of having to know a finding's hash up front.
+///
+/// * `hounddog:ignore` suppresses every detector on the next non-blank, non-comment source
+///   line; rule ids after it (`hounddog:ignore rule-a`) limit it to the named detectors.
+/// * `hounddog:ignore-line rule-a,rule-b` suppresses the named detectors on the comment's
+///   own line; with no rule ids it suppresses every detector on that line.
+pub struct SuppressionIndex {
+    entries: Vec<Suppression>,
+}
+
+impl SuppressionIndex {
+    pub fn new(source: &[u8]) -> SuppressionIndex {
+        let text = String::from_utf8_lossy(source);
+        let lines: Vec<&str> = text.lines().collect();
+        let mut entries = Vec::new();
+
+        for (i, raw) in lines.iter().enumerate() {
+            let Some(body) = comment_body(raw.trim_start()) else { continue };
+            let body = body.trim();
+
+            if let Some(args) = body.strip_prefix("hounddog:ignore-line") {
+                entries.push(Suppression { line: i + 1, rules: parse_rule_ids(args) });
+            } else if body == "hounddog:ignore" || body.starts_with("hounddog:ignore ") {
+                let args = body.strip_prefix("hounddog:ignore").unwrap_or_default();
+                // Resolve to the next line that is neither blank nor a comment.
+                if let Some(target) = lines
+                    .iter()
+                    .enumerate()
+                    .skip(i + 1)
+                    .find(|(_, l)| !l.trim().is_empty() && comment_body(l.trim_start()).is_none())
+                    .map(|(j, _)| j + 1)
+                {
+                    entries.push(Suppression { line: target, rules: parse_rule_ids(args) });
+                }
+            }
+        }
+        SuppressionIndex { entries }
+    }
+
+    /// Whether a finding for `rule_id` on `line` is suppressed by an inline directive.
+    pub fn is_suppressed(&self, line: usize, rule_id: &str) -> bool {
+        self.entries.iter().any(|entry| {
+            entry.line == line
+                && match &entry.rules {
+                    Some(rules) => rules.iter().any(|r| r == rule_id),
+                    None => true,
+                }
+        })
+    }
+}
+
+/// Extract the text following a line-comment marker (`//` or `#`), if the line is a comment.
+fn comment_body(line: &str) -> Option<&str> {
+    line.strip_prefix("//").or_else(|| line.strip_prefix('#'))
+}
+
+/// Parse a comma/whitespace separated list of rule ids, returning `None` when empty.
+fn parse_rule_ids(args: &str) -> Option<Vec<String>> {
+    let rules: Vec<String> = args
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect();
+    if rules.is_empty() {
+        None
+    } else {
+        Some(rules)
+    }
+}
+
 pub fn get_child_by_field<'a>(node: &'a Node, field: &str) -> Node<'a> {
     node.child_by_field_name(field).unwrap()
 }
@@ -1,3 +1,4 @@
+use std::cell::{RefCell, RefMut};
 use std::fmt::Debug;
 use std::path::Path;
 use std::str::FromStr;
@@ -5,10 +6,13 @@ use std::str::FromStr;
 use anyhow::Result;
 use rusqlite::Connection as SqliteConnection;
 
-use crate::structs::{DataElementOccurrence, Vulnerability};
+use crate::scanner::taint::TaintCollector;
+use crate::structs::{DataElementOccurrence, GraphQLFinding, Vulnerability};
 
 pub struct ScanDatabase {
     conn: SqliteConnection,
+    /// Interprocedural taint facts gathered during scanning, resolved in a post-pass.
+    taint: RefCell<TaintCollector>,
 }
 
 /// Database for recording and querying information extracted from scans.
@@ -56,14 +60,37 @@ impl ScanDatabase {
                 column_end INT,
                 url_link TEXT,
                 cwe TEXT,
-                owasp TEXT
+                owasp TEXT,
+                sanitized_by TEXT,
+                code_frame TEXT
+            );
+
+            DROP TABLE IF EXISTS graphql_findings;
+            CREATE TABLE graphql_findings(
+                data_element_ids TEXT,
+                type_name TEXT,
+                field_name TEXT,
+                categories TEXT,
+                hash TEXT,
+                language VARCHAR(10),
+                code_segment TEXT,
+                relative_file_path TEXT,
+                absolute_file_path TEXT,
+                line INT,
+                column INT,
+                deprecated INT
             );
 
             COMMIT;
             ",
         )
         .unwrap();
-        Self { conn }
+        Self { conn, taint: RefCell::new(TaintCollector::default()) }
+    }
+
+    /// Mutable handle to the taint collector for scanners to record flow facts.
+    pub fn taint(&self) -> RefMut<TaintCollector> {
+        self.taint.borrow_mut()
     }
 
     pub fn put_data_element_occurrence(&self, occurrence: &DataElementOccurrence) -> Result<()> {
@@ -148,6 +175,76 @@ impl ScanDatabase {
         Ok(rows.map(Result::unwrap).collect())
     }
 
+    pub fn put_graphql_finding(&self, finding: &GraphQLFinding) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO graphql_findings (
+                data_element_ids,
+                type_name,
+                field_name,
+                categories,
+                hash,
+                language,
+                code_segment,
+                relative_file_path,
+                absolute_file_path,
+                line,
+                column,
+                deprecated
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+            [
+                &finding.data_element_ids.join(","),
+                &finding.type_name,
+                &finding.field_name,
+                &finding.categories.join(","),
+                &finding.hash,
+                &finding.language.to_string(),
+                &finding.code_segment,
+                &finding.relative_file_path,
+                &finding.absolute_file_path,
+                &finding.line.to_string(),
+                &finding.column.to_string(),
+                &(finding.deprecated as i32).to_string(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_graphql_findings(&self) -> Result<Vec<GraphQLFinding>> {
+        let mut statement = self.conn.prepare(
+            "SELECT
+                data_element_ids,
+                type_name,
+                field_name,
+                categories,
+                hash,
+                language,
+                code_segment,
+                relative_file_path,
+                absolute_file_path,
+                line,
+                column,
+                deprecated
+            FROM graphql_findings",
+        )?;
+        let rows = statement.query_map([], |row| {
+            Ok(GraphQLFinding {
+                data_element_ids: row_to_vec(row, 0),
+                type_name: row.get(1)?,
+                field_name: row.get(2)?,
+                categories: row_to_vec(row, 3),
+                hash: row.get(4)?,
+                language: row_to_enum(row, 5),
+                code_segment: row.get(6)?,
+                relative_file_path: row.get(7)?,
+                absolute_file_path: row.get(8)?,
+                line: row.get(9)?,
+                column: row.get(10)?,
+                deprecated: row.get::<_, i32>(11)? != 0,
+            })
+        })?;
+        Ok(rows.map(Result::unwrap).collect())
+    }
+
     pub fn put_vulnerability(&self, vulnerability: &Vulnerability) -> Result<()> {
         self.conn.execute(
             "INSERT INTO vulnerabilities (
@@ -167,8 +264,10 @@ impl ScanDatabase {
                 column_end,
                 url_link,
                 cwe,
-                owasp
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)",
+                owasp,
+                sanitized_by,
+                code_frame
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19)",
             [
                 &vulnerability.data_sink_id,
                 &vulnerability.data_element_ids.join(","),
@@ -187,6 +286,12 @@ impl ScanDatabase {
                 &vulnerability.url_link,
                 &vulnerability.cwe.join(","),
                 &vulnerability.owasp.join(","),
+                &vulnerability.sanitized_by.clone().unwrap_or_default(),
+                &vulnerability
+                    .code_frame
+                    .as_ref()
+                    .map(|frame| serde_json::to_string(frame).unwrap())
+                    .unwrap_or_default(),
             ],
         )?;
         Ok(())
@@ -211,7 +316,9 @@ impl ScanDatabase {
                 column_end,
                 url_link,
                 cwe,
-                owasp
+                owasp,
+                sanitized_by,
+                code_frame
             FROM vulnerabilities",
         )?;
         let rows = statement.query_map([], |row| {
@@ -233,6 +340,14 @@ impl ScanDatabase {
                 url_link: row.get(14)?,
                 cwe: row_to_vec(row, 15),
                 owasp: row_to_vec(row, 16),
+                sanitized_by: {
+                    let value: String = row.get(17)?;
+                    if value.is_empty() { None } else { Some(value) }
+                },
+                code_frame: {
+                    let value: String = row.get(18)?;
+                    if value.is_empty() { None } else { serde_json::from_str(&value).ok() }
+                },
             })
         })?;
         Ok(rows.map(Result::unwrap).collect())
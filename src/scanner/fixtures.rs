@@ -0,0 +1,178 @@
+//! Fixture-based snapshot test harness for detectors.
+//!
+//! A fixture is an ordinary source file annotated with caret markers in trailing comments
+//! that declare the findings a detector should produce. For example:
+//!
+//! ```text
+//! password = "hunter2"
+//! #          ^^^^^^^^^ secret
+//! ```
+//!
+//! The `^` run marks the column span (1-based, inclusive) of the expected finding on the
+//! nearest preceding non-annotation line, and the trailing token names the rule (data
+//! element or data sink id). [`parse_fixture`] strips the annotation lines and returns the
+//! clean source together with the expected findings, so a test can run the scanner over the
+//! source and compare the produced ranges against the markers. On mismatch [`assert_findings`]
+//! renders a line-by-line diff rather than a bare `assert_eq`, so failures read clearly.
+
+use std::fmt::Write as _;
+
+/// An expected finding extracted from a fixture's caret annotation.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExpectedFinding {
+    pub line: usize,
+    pub column_start: usize,
+    pub column_end: usize,
+    pub rule: String,
+}
+
+/// Parse an annotated fixture into its clean source and the set of expected findings.
+pub fn parse_fixture(annotated: &str) -> (String, Vec<ExpectedFinding>) {
+    let mut source_lines: Vec<&str> = Vec::new();
+    let mut expected: Vec<ExpectedFinding> = Vec::new();
+
+    for raw in annotated.lines() {
+        match caret_annotation(raw) {
+            Some((column_start, column_end, rule)) => {
+                // Annotations attach to the most recent real source line (1-based).
+                let line = source_lines.len();
+                if line > 0 {
+                    expected.push(ExpectedFinding {
+                        line,
+                        column_start,
+                        column_end,
+                        rule: rule.to_string(),
+                    });
+                }
+            }
+            None => source_lines.push(raw),
+        }
+    }
+
+    expected.sort();
+    (source_lines.join("\n"), expected)
+}
+
+/// If `line` is a caret annotation comment, return its (1-based inclusive) column span and
+/// the rule id. A caret annotation is a comment whose body is a run of `^` plus a rule token.
+fn caret_annotation(line: &str) -> Option<(usize, usize, &str)> {
+    let first_caret = line.find('^')?;
+    let last_caret = line.rfind('^')?;
+    // Everything before the carets must be comment punctuation / whitespace only.
+    if !line[..first_caret].chars().all(|c| c.is_whitespace() || c == '/' || c == '#') {
+        return None;
+    }
+    let rule = line[last_caret + 1..].trim();
+    if rule.is_empty() {
+        return None;
+    }
+    // Carets are 0-based byte columns here; report 1-based inclusive columns.
+    Some((first_caret + 1, last_caret + 1, rule))
+}
+
+/// Assert the produced findings match the expected set, panicking with a readable diff.
+pub fn assert_findings(expected: &[ExpectedFinding], actual: &[ExpectedFinding]) {
+    let mut expected = expected.to_vec();
+    let mut actual = actual.to_vec();
+    expected.sort();
+    actual.sort();
+    if expected != actual {
+        panic!("fixture findings mismatch:\n{}", diff(&expected, &actual));
+    }
+}
+
+/// Render a line-by-line diff between the expected and actual findings, prefixing removed
+/// (expected-only) lines with `-` and added (actual-only) lines with `+`.
+fn diff(expected: &[ExpectedFinding], actual: &[ExpectedFinding]) -> String {
+    let expected: Vec<String> = expected.iter().map(render).collect();
+    let actual: Vec<String> = actual.iter().map(render).collect();
+
+    // Longest common subsequence over the rendered lines, the core of a dissimilar-style diff.
+    let (m, n) = (expected.len(), actual.len());
+    let mut table = vec![vec![0usize; n + 1]; m + 1];
+    for i in (0..m).rev() {
+        for j in (0..n).rev() {
+            table[i][j] = if expected[i] == actual[j] {
+                table[i + 1][j + 1] + 1
+            } else {
+                table[i + 1][j].max(table[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < m && j < n {
+        if expected[i] == actual[j] {
+            let _ = writeln!(out, "  {}", expected[i]);
+            i += 1;
+            j += 1;
+        } else if table[i + 1][j] >= table[i][j + 1] {
+            let _ = writeln!(out, "- {}", expected[i]);
+            i += 1;
+        } else {
+            let _ = writeln!(out, "+ {}", actual[j]);
+            j += 1;
+        }
+    }
+    for line in &expected[i..] {
+        let _ = writeln!(out, "- {}", line);
+    }
+    for line in &actual[j..] {
+        let _ = writeln!(out, "+ {}", line);
+    }
+    out
+}
+
+fn render(finding: &ExpectedFinding) -> String {
+    format!(
+        "{}:{}-{} {}",
+        finding.line, finding.column_start, finding.column_end, finding.rule
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_caret_annotations_and_strips_them() {
+        let fixture = "password = \"hunter2\"\n#          ^^^^^^^^^ secret\nx = 1\n";
+        let (source, expected) = parse_fixture(fixture);
+
+        assert_eq!(source, "password = \"hunter2\"\nx = 1");
+        assert_eq!(
+            expected,
+            vec![ExpectedFinding {
+                line: 1,
+                column_start: 12,
+                column_end: 20,
+                rule: "secret".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn assert_findings_accepts_matching_sets() {
+        let finding = ExpectedFinding {
+            line: 1,
+            column_start: 12,
+            column_end: 20,
+            rule: "secret".to_string(),
+        };
+        assert_findings(&[finding.clone()], &[finding]);
+    }
+
+    #[test]
+    #[should_panic(expected = "fixture findings mismatch")]
+    fn assert_findings_reports_mismatch() {
+        let expected = ExpectedFinding {
+            line: 1,
+            column_start: 12,
+            column_end: 20,
+            rule: "secret".to_string(),
+        };
+        let actual = ExpectedFinding { rule: "token".to_string(), ..expected.clone() };
+        assert_findings(&[expected], &[actual]);
+    }
+}
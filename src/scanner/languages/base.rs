@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use regex::Regex;
@@ -12,6 +13,17 @@ use crate::structs::FileScanContext;
 use crate::structs::ScanConfig;
 use crate::utils::file::get_file_language;
 
+/// Wall-clock time `BaseScanner::scan_file_timed` spent in each phase of a single file's scan,
+/// for the benchmark harness (see [`crate::bench`]) to aggregate into throughput figures.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FileScanTimings {
+    /// Time spent producing the AST from source bytes.
+    pub parse: Duration,
+    /// Time spent walking the AST (`visit_node`/`leave_node`), which is also where occurrences
+    /// and intra-file vulnerabilities are emitted into `database` as a side effect of visiting.
+    pub visit: Duration,
+}
+
 pub trait BaseScanner {
     /// Visit a node in the abstract syntax tree (AST).
     ///
@@ -43,11 +55,27 @@ pub trait BaseScanner {
         parser: &mut Parser,
         file_path: &PathBuf,
     ) -> Result<()> {
+        Self::scan_file_timed(database, config, parser, file_path).map(|_| ())
+    }
+
+    /// Same as [`scan_file`](BaseScanner::scan_file), but returns how long parsing and
+    /// AST-walking each took, for the benchmark harness to report throughput and catch
+    /// performance regressions across commits.
+    fn scan_file_timed(
+        database: &ScanDatabase,
+        config: &ScanConfig,
+        parser: &mut Parser,
+        file_path: &PathBuf,
+    ) -> Result<FileScanTimings> {
         let source = std::fs::read(file_path)?;
+
+        let parse_start = Instant::now();
         let ast = parser
             .parse(&source, None)
             .ok_or(err!("Failed to parse {}", file_path.display()))?;
+        let parse = parse_start.elapsed();
 
+        let visit_start = Instant::now();
         let mut context = FileScanContext::new(database, config, file_path, &source);
         let mut cursor = ast.walk();
         let mut visited_all_children = false;
@@ -65,7 +93,7 @@ pub trait BaseScanner {
             } else {
                 Self::leave_node(&mut context, &node);
                 if !cursor.goto_parent() {
-                    return Ok(());
+                    return Ok(FileScanTimings { parse, visit: visit_start.elapsed() });
                 }
             }
         }
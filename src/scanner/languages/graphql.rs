@@ -1,19 +1,756 @@
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
 
-use graphql_parser::parse_schema;
+use anyhow::Result;
+use graphql_parser::query::{
+    parse_query, Definition as QueryDefinition, FragmentDefinition, OperationDefinition, Selection,
+    SelectionSet,
+};
+use graphql_parser::schema::{
+    parse_schema, Definition, Document, Field, InputValue, Pos, Type, TypeDefinition, TypeExtension,
+};
+use serde::Deserialize;
 
+use crate::enums::Language;
+use crate::err;
 use crate::scanner::database::ScanDatabase;
-use crate::structs::ScanConfig;
+use crate::structs::{DataElement, DataElementOccurrence, GraphQLConfig, GraphQLFinding, ScanConfig};
+use crate::utils::file::get_files_in_dir;
+use crate::utils::git::get_url_link;
+use crate::utils::hash::calculate_md5_hash;
 
 pub struct GraphQLScanner;
 
+/// A type/field representation shared by the SDL and introspection paths.
+struct NormalizedType {
+    name: String,
+    fields: Vec<NormalizedField>,
+}
+
+struct NormalizedField {
+    name: String,
+    type_name: String,
+    position: Pos,
+    /// Categories forced by a `@sensitive(category: ...)` directive, bypassing name heuristics.
+    forced: Vec<String>,
+    /// Set by `@sensitive(ignore: true)` to suppress a name-heuristic match.
+    ignore: bool,
+    /// Set by `@deprecated(reason: ...)`.
+    deprecated: bool,
+}
+
+impl Default for FieldDirectives {
+    fn default() -> Self {
+        FieldDirectives { forced: vec![], ignore: false, deprecated: false }
+    }
+}
+
+struct FieldDirectives {
+    forced: Vec<String>,
+    ignore: bool,
+    deprecated: bool,
+}
+
 impl GraphQLScanner {
-    fn scan(
-        &mut self,
+    pub fn scan_file(
         database: &ScanDatabase,
         config: &ScanConfig,
-        file_path: &Path,
-    ) -> anyhow::Result<()> {
-        Ok(())
+        file_path: &PathBuf,
+    ) -> Result<()> {
+        let source = std::fs::read_to_string(file_path)?;
+
+        // A dumped introspection response (`{ "data": { "__schema": { ... } } }`).
+        if file_path.extension().and_then(|ext| ext.to_str()) == Some("json") {
+            let types = normalize_introspection(&source, file_path)?;
+            return scan_normalized(database, config, file_path, &source, &types);
+        }
+
+        // SDL schemas carry type definitions; operation documents do not. Prefer the SDL
+        // path when the file declares types, otherwise treat it as an operation document.
+        match parse_schema::<String>(&source) {
+            Ok(document) if has_type_definitions(&document) => {
+                let types = collect_sdl_types(&document, &config.graphql);
+                scan_normalized(database, config, file_path, &source, &types)
+            }
+            _ => scan_operations(database, config, file_path, &source),
+        }
+    }
+}
+
+fn has_type_definitions(document: &Document<String>) -> bool {
+    document.definitions.iter().any(|def| {
+        matches!(def, Definition::TypeDefinition(_) | Definition::TypeExtension(_))
+    })
+}
+
+/// Collect object/interface/input type definitions (and extensions) from SDL.
+fn collect_sdl_types(document: &Document<String>, config: &GraphQLConfig) -> Vec<NormalizedType> {
+    let mut types = vec![];
+    for definition in &document.definitions {
+        match definition {
+            Definition::TypeDefinition(type_def) => {
+                collect_type_definition(type_def, config, &mut types)
+            }
+            // `extend type User { ... }` — scan the extension's fields too.
+            Definition::TypeExtension(type_ext) => {
+                collect_type_extension(type_ext, config, &mut types)
+            }
+            _ => {}
+        }
+    }
+    types
+}
+
+fn collect_type_definition(
+    type_def: &TypeDefinition<String>,
+    config: &GraphQLConfig,
+    out: &mut Vec<NormalizedType>,
+) {
+    let (name, fields) = match type_def {
+        TypeDefinition::Object(obj) => (&obj.name, field_specs(&obj.fields, config)),
+        TypeDefinition::Interface(iface) => (&iface.name, field_specs(&iface.fields, config)),
+        TypeDefinition::InputObject(input) => {
+            (&input.name, input_field_specs(&input.fields, config))
+        }
+        _ => return,
+    };
+    out.push(NormalizedType { name: name.clone(), fields });
+}
+
+fn collect_type_extension(
+    type_ext: &TypeExtension<String>,
+    config: &GraphQLConfig,
+    out: &mut Vec<NormalizedType>,
+) {
+    let (name, fields) = match type_ext {
+        TypeExtension::Object(obj) => (&obj.name, field_specs(&obj.fields, config)),
+        TypeExtension::Interface(iface) => (&iface.name, field_specs(&iface.fields, config)),
+        TypeExtension::InputObject(input) => {
+            (&input.name, input_field_specs(&input.fields, config))
+        }
+        _ => return,
+    };
+    out.push(NormalizedType { name: name.clone(), fields });
+}
+
+fn field_specs(fields: &[Field<String>], config: &GraphQLConfig) -> Vec<NormalizedField> {
+    fields
+        .iter()
+        .map(|f| {
+            let directives = parse_directives(&f.directives, config);
+            NormalizedField {
+                name: f.name.clone(),
+                type_name: named_type_of(&f.field_type).to_string(),
+                position: f.position,
+                forced: directives.forced,
+                ignore: directives.ignore,
+                deprecated: directives.deprecated,
+            }
+        })
+        .collect()
+}
+
+fn input_field_specs(fields: &[InputValue<String>], config: &GraphQLConfig) -> Vec<NormalizedField> {
+    fields
+        .iter()
+        .map(|f| {
+            let directives = parse_directives(&f.directives, config);
+            NormalizedField {
+                name: f.name.clone(),
+                type_name: named_type_of(&f.value_type).to_string(),
+                position: f.position,
+                forced: directives.forced,
+                ignore: directives.ignore,
+                deprecated: directives.deprecated,
+            }
+        })
+        .collect()
+}
+
+/// Interpret `@sensitive(...)` and `@deprecated(...)` directives on a field.
+fn parse_directives(
+    directives: &[graphql_parser::schema::Directive<String>],
+    config: &GraphQLConfig,
+) -> FieldDirectives {
+    let mut result = FieldDirectives::default();
+    for directive in directives {
+        if directive.name == config.directive {
+            for (arg, value) in &directive.arguments {
+                if *arg == config.category_arg {
+                    if let Some(text) = coerce_string(value) {
+                        result.forced.push(text);
+                    }
+                } else if *arg == config.ignore_arg {
+                    result.ignore = matches!(value, graphql_parser::query::Value::Boolean(true));
+                }
+                // Any other argument, including `level_arg`, is not read.
+            }
+        } else if directive.name == "deprecated" {
+            result.deprecated = true;
+        }
+    }
+    result
+}
+
+fn coerce_string(value: &graphql_parser::query::Value<String>) -> Option<String> {
+    use graphql_parser::query::Value;
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Enum(s) => Some(s.clone()),
+        Value::Boolean(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Unwrap `NonNull`/`List` wrappers down to the underlying named type.
+fn named_type_of<'a>(field_type: &'a Type<'a, String>) -> &'a str {
+    match field_type {
+        Type::NamedType(name) => name,
+        Type::ListType(inner) => named_type_of(inner),
+        Type::NonNullType(inner) => named_type_of(inner),
+    }
+}
+
+// --- Introspection JSON model -------------------------------------------------
+
+#[derive(Deserialize)]
+struct IntrospectionResponse {
+    data: IntrospectionData,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionData {
+    #[serde(rename = "__schema")]
+    schema: IntrospectionSchema,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionSchema {
+    types: Vec<IntrospectionType>,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionType {
+    name: Option<String>,
+    #[serde(default)]
+    fields: Option<Vec<IntrospectionField>>,
+    #[serde(rename = "inputFields", default)]
+    input_fields: Option<Vec<IntrospectionField>>,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionField {
+    name: String,
+    #[serde(rename = "type")]
+    field_type: IntrospectionTypeRef,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionTypeRef {
+    kind: String,
+    name: Option<String>,
+    #[serde(rename = "ofType")]
+    of_type: Option<Box<IntrospectionTypeRef>>,
+}
+
+impl IntrospectionTypeRef {
+    /// Follow the `ofType` chain (`NON_NULL` → `LIST` → ... → named kind).
+    fn named(&self) -> &str {
+        match &self.of_type {
+            Some(inner) => inner.named(),
+            None => self.name.as_deref().unwrap_or_default(),
+        }
+    }
+}
+
+fn normalize_introspection(source: &str, file_path: &PathBuf) -> Result<Vec<NormalizedType>> {
+    let response: IntrospectionResponse = serde_json::from_str(source)
+        .map_err(|e| err!("Failed to parse introspection JSON {}: {e}", file_path.display()))?;
+
+    let mut types = vec![];
+    for ty in response.data.schema.types {
+        let Some(name) = ty.name else { continue };
+        let mut fields = vec![];
+        for field in ty.fields.into_iter().flatten().chain(ty.input_fields.into_iter().flatten()) {
+            fields.push(NormalizedField {
+                name: field.name,
+                type_name: field.field_type.named().to_string(),
+                // Introspection JSON carries no source position or directives.
+                position: Pos { line: 0, column: 0 },
+                forced: vec![],
+                ignore: false,
+                deprecated: false,
+            });
+        }
+        types.push(NormalizedType { name, fields });
+    }
+    Ok(types)
+}
+
+// --- Detection ----------------------------------------------------------------
+
+fn scan_normalized(
+    database: &ScanDatabase,
+    config: &ScanConfig,
+    file_path: &PathBuf,
+    source: &str,
+    types: &[NormalizedType],
+) -> Result<()> {
+    for ty in types {
+        // Skip introspection meta-types (e.g. `__Type`, `__Schema`).
+        if ty.name.starts_with("__") {
+            continue;
+        }
+        for field in &ty.fields {
+            if field.name.starts_with("__") {
+                continue;
+            }
+            // `@sensitive(ignore: true)` suppresses whatever the name heuristic would flag.
+            if field.ignore {
+                continue;
+            }
+
+            // Collapse multiple matching patterns for one field into a single finding
+            // carrying every matched category.
+            let mut categories: Vec<String> = field.forced.clone();
+            let mut matched: Vec<&DataElement> = vec![];
+            for data_element in config.data_elements.values() {
+                if !data_element.is_enabled {
+                    continue;
+                }
+                if data_element.is_match(&field.name) || data_element.is_match(&field.type_name) {
+                    if !categories.contains(&data_element.name) {
+                        categories.push(data_element.name.clone());
+                    }
+                    matched.push(data_element);
+                }
+            }
+            // A `@sensitive` directive forces a finding even without a name-heuristic match.
+            if matched.is_empty() && field.forced.is_empty() {
+                continue;
+            }
+
+            let finding = GraphQLFinding::from_schema_field(
+                config,
+                file_path,
+                &ty.name,
+                &field.name,
+                categories,
+                &matched,
+                field.position,
+                extract_line(source, field.position.line),
+                field.deprecated,
+            );
+            database.put_graphql_finding(&finding)?;
+        }
+    }
+    Ok(())
+}
+
+// --- Operation documents ------------------------------------------------------
+
+/// A resolvable view of the repository's schema: type name → (field → return type),
+/// plus the configured root operation type names.
+struct SchemaIndex {
+    fields: HashMap<String, HashMap<String, String>>,
+    query_root: String,
+    mutation_root: String,
+    subscription_root: String,
+}
+
+impl SchemaIndex {
+    /// Build an index by parsing every SDL schema file found under the repository root,
+    /// reusing the process-wide [`ParsedSchema`] cache so a file is reparsed only when its mtime
+    /// changes.
+    fn build(config: &ScanConfig) -> SchemaIndex {
+        let mut index = SchemaIndex {
+            fields: HashMap::new(),
+            query_root: "Query".to_string(),
+            mutation_root: "Mutation".to_string(),
+            subscription_root: "Subscription".to_string(),
+        };
+
+        // Canonicalize into a set so symlinked/duplicate paths collapse to one entry.
+        let mut canonical_paths: std::collections::BTreeSet<PathBuf> = Default::default();
+        for file in get_files_in_dir(
+            &config.repository.path,
+            config.include_globs.as_ref(),
+            config.exclude_globs.as_ref(),
+        ) {
+            match file.extension().and_then(|ext| ext.to_str()) {
+                Some("graphql") | Some("gql") => {}
+                _ => continue,
+            }
+            if let Ok(path) = std::fs::canonicalize(&file) {
+                canonical_paths.insert(path);
+            }
+        }
+
+        for path in canonical_paths {
+            let Some(parsed) = parsed_schema_cached(&path, &config.graphql) else { continue };
+            if let Some(q) = &parsed.query_root {
+                index.query_root = q.clone();
+            }
+            if let Some(m) = &parsed.mutation_root {
+                index.mutation_root = m.clone();
+            }
+            if let Some(s) = &parsed.subscription_root {
+                index.subscription_root = s.clone();
+            }
+            for (type_name, type_fields) in &parsed.fields {
+                let entry = index.fields.entry(type_name.clone()).or_default();
+                for (field, return_type) in type_fields {
+                    entry.insert(field.clone(), return_type.clone());
+                }
+            }
+        }
+        index
+    }
+
+    fn return_type(&self, type_name: &str, field_name: &str) -> Option<&str> {
+        self.fields.get(type_name).and_then(|m| m.get(field_name)).map(String::as_str)
+    }
+}
+
+/// Normalized, owned schema representation stored in the cache (no borrowed AST).
+struct ParsedSchema {
+    fields: HashMap<String, HashMap<String, String>>,
+    query_root: Option<String>,
+    mutation_root: Option<String>,
+    subscription_root: Option<String>,
+}
+
+/// Process-wide cache keyed by canonical path, additionally keyed on mtime so a file
+/// edited between runs within a long-lived process is reparsed.
+fn schema_cache() -> &'static Mutex<BTreeMap<PathBuf, (SystemTime, Arc<ParsedSchema>)>> {
+    static CACHE: OnceLock<Mutex<BTreeMap<PathBuf, (SystemTime, Arc<ParsedSchema>)>>> =
+        OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(BTreeMap::new()))
+}
+
+fn parsed_schema_cached(path: &PathBuf, config: &GraphQLConfig) -> Option<Arc<ParsedSchema>> {
+    let mtime = std::fs::metadata(path).and_then(|m| m.modified()).ok()?;
+
+    {
+        let cache = schema_cache().lock().unwrap();
+        if let Some((cached_mtime, parsed)) = cache.get(path) {
+            if *cached_mtime == mtime {
+                return Some(Arc::clone(parsed));
+            }
+        }
+    }
+
+    let source = std::fs::read_to_string(path).ok()?;
+    let document = parse_schema::<String>(&source).ok()?;
+
+    let mut parsed = ParsedSchema {
+        fields: HashMap::new(),
+        query_root: None,
+        mutation_root: None,
+        subscription_root: None,
+    };
+    for definition in &document.definitions {
+        if let Definition::SchemaDefinition(schema) = definition {
+            parsed.query_root = schema.query.clone();
+            parsed.mutation_root = schema.mutation.clone();
+            parsed.subscription_root = schema.subscription.clone();
+        }
+    }
+    for ty in collect_sdl_types(&document, config) {
+        let entry = parsed.fields.entry(ty.name).or_default();
+        for field in ty.fields {
+            entry.insert(field.name, field.type_name);
+        }
+    }
+
+    let parsed = Arc::new(parsed);
+    schema_cache().lock().unwrap().insert(path.clone(), (mtime, Arc::clone(&parsed)));
+    Some(parsed)
+}
+
+fn scan_operations(
+    database: &ScanDatabase,
+    config: &ScanConfig,
+    file_path: &PathBuf,
+    source: &str,
+) -> Result<()> {
+    let document = parse_query::<String>(source)
+        .map_err(|e| err!("Failed to parse GraphQL operations {}: {e}", file_path.display()))?;
+
+    let index = SchemaIndex::build(config);
+
+    // Collect fragment definitions so spreads can be resolved against them.
+    let mut fragments: HashMap<String, &FragmentDefinition<String>> = HashMap::new();
+    for definition in &document.definitions {
+        if let QueryDefinition::Fragment(fragment) = definition {
+            fragments.insert(fragment.name.clone(), fragment);
+        }
+    }
+
+    for definition in &document.definitions {
+        if let QueryDefinition::Operation(operation) = definition {
+            let (op_name, root_type, selection_set) = match operation {
+                OperationDefinition::Query(q) => (
+                    q.name.clone().unwrap_or_else(|| "anonymous".to_string()),
+                    index.query_root.clone(),
+                    &q.selection_set,
+                ),
+                OperationDefinition::Mutation(m) => (
+                    m.name.clone().unwrap_or_else(|| "anonymous".to_string()),
+                    index.mutation_root.clone(),
+                    &m.selection_set,
+                ),
+                OperationDefinition::Subscription(s) => (
+                    s.name.clone().unwrap_or_else(|| "anonymous".to_string()),
+                    index.subscription_root.clone(),
+                    &s.selection_set,
+                ),
+                // Shorthand query (`{ ... }`) is rooted at the query type.
+                OperationDefinition::SelectionSet(set) => {
+                    ("anonymous".to_string(), index.query_root.clone(), set)
+                }
+            };
+            walk_selection_set(
+                database,
+                config,
+                file_path,
+                source,
+                &index,
+                &fragments,
+                &op_name,
+                &root_type,
+                &[],
+                selection_set,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn walk_selection_set(
+    database: &ScanDatabase,
+    config: &ScanConfig,
+    file_path: &PathBuf,
+    source: &str,
+    index: &SchemaIndex,
+    fragments: &HashMap<String, &FragmentDefinition<String>>,
+    op_name: &str,
+    current_type: &str,
+    path: &[String],
+    selection_set: &SelectionSet<String>,
+) -> Result<()> {
+    for selection in &selection_set.items {
+        match selection {
+            Selection::Field(field) => {
+                let mut field_path = path.to_vec();
+                field_path.push(field.name.clone());
+
+                // Only flag a selected field that both matches a sensitive pattern and is
+                // reachable (resolvable) against the current type.
+                let return_type = index.return_type(current_type, &field.name);
+                let mut categories: Vec<String> = vec![];
+                let mut matched: Vec<&DataElement> = vec![];
+                for data_element in config.data_elements.values() {
+                    if data_element.is_enabled && data_element.is_match(&field.name) {
+                        if !categories.contains(&data_element.name) {
+                            categories.push(data_element.name.clone());
+                        }
+                        matched.push(data_element);
+                    }
+                }
+                if !matched.is_empty() && return_type.is_some() {
+                    let finding = GraphQLFinding::from_operation_field(
+                        config,
+                        file_path,
+                        op_name,
+                        &field_path,
+                        categories,
+                        &matched,
+                        field.position,
+                        extract_line(source, field.position.line),
+                    );
+                    database.put_graphql_finding(&finding)?;
+                }
+
+                if let Some(next_type) = return_type {
+                    walk_selection_set(
+                        database,
+                        config,
+                        file_path,
+                        source,
+                        index,
+                        fragments,
+                        op_name,
+                        next_type,
+                        &field_path,
+                        &field.selection_set,
+                    )?;
+                }
+            }
+            Selection::InlineFragment(inline) => {
+                let next_type = inline
+                    .type_condition
+                    .as_ref()
+                    .map(|tc| match tc {
+                        graphql_parser::query::TypeCondition::On(name) => name.as_str(),
+                    })
+                    .unwrap_or(current_type);
+                walk_selection_set(
+                    database,
+                    config,
+                    file_path,
+                    source,
+                    index,
+                    fragments,
+                    op_name,
+                    next_type,
+                    path,
+                    &inline.selection_set,
+                )?;
+            }
+            Selection::FragmentSpread(spread) => {
+                if let Some(fragment) = fragments.get(&spread.fragment_name) {
+                    let graphql_parser::query::TypeCondition::On(frag_type) =
+                        &fragment.type_condition;
+                    walk_selection_set(
+                        database,
+                        config,
+                        file_path,
+                        source,
+                        index,
+                        fragments,
+                        op_name,
+                        frag_type,
+                        path,
+                        &fragment.selection_set,
+                    )?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Extract the 1-based source line for a finding's code segment.
+fn extract_line(source: &str, line: usize) -> String {
+    source.lines().nth(line.saturating_sub(1)).unwrap_or_default().trim().to_string()
+}
+
+impl GraphQLFinding {
+    #[allow(clippy::too_many_arguments)]
+    fn from_schema_field(
+        config: &ScanConfig,
+        file_path: &PathBuf,
+        type_name: &str,
+        field_name: &str,
+        categories: Vec<String>,
+        data_elements: &[&DataElement],
+        position: Pos,
+        code_segment: String,
+        deprecated: bool,
+    ) -> GraphQLFinding {
+        let relative_file_path = file_path
+            .strip_prefix(&config.repository.path)
+            .unwrap_or(file_path)
+            .display()
+            .to_string();
+        GraphQLFinding {
+            data_element_ids: data_elements.iter().map(|e| e.id.clone()).collect(),
+            type_name: type_name.to_string(),
+            field_name: field_name.to_string(),
+            categories,
+            hash: calculate_md5_hash(format!(
+                "{}|{}|{}|{}|{}",
+                config.repository.name,
+                config.repository.branch,
+                relative_file_path,
+                type_name,
+                field_name,
+            )),
+            language: Language::GraphQL,
+            code_segment,
+            relative_file_path,
+            absolute_file_path: file_path.display().to_string(),
+            line: position.line,
+            column: position.column,
+            deprecated,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn from_operation_field(
+        config: &ScanConfig,
+        file_path: &PathBuf,
+        operation_name: &str,
+        field_path: &[String],
+        categories: Vec<String>,
+        data_elements: &[&DataElement],
+        position: Pos,
+        code_segment: String,
+    ) -> GraphQLFinding {
+        let relative_file_path = file_path
+            .strip_prefix(&config.repository.path)
+            .unwrap_or(file_path)
+            .display()
+            .to_string();
+        let field_path = field_path.join(".");
+        GraphQLFinding {
+            data_element_ids: data_elements.iter().map(|e| e.id.clone()).collect(),
+            type_name: operation_name.to_string(),
+            field_name: field_path.clone(),
+            categories,
+            hash: calculate_md5_hash(format!(
+                "{}|{}|{}|{}|{}",
+                config.repository.name,
+                config.repository.branch,
+                relative_file_path,
+                operation_name,
+                field_path,
+            )),
+            language: Language::GraphQL,
+            code_segment,
+            relative_file_path,
+            absolute_file_path: file_path.display().to_string(),
+            line: position.line,
+            column: position.column,
+            deprecated: false,
+        }
+    }
+
+    /// Project this finding onto one [`DataElementOccurrence`] per flagged data element, so a
+    /// sensitive field exposed through a GraphQL schema or operation is reported the same way
+    /// every other sensitive-data sighting is, instead of being stranded in its own table.
+    pub fn to_occurrences(&self, config: &ScanConfig) -> Vec<DataElementOccurrence> {
+        self.data_element_ids
+            .iter()
+            .filter_map(|id| config.data_elements.get(id))
+            .map(|data_element| DataElementOccurrence {
+                data_element_id: data_element.id.clone(),
+                data_element_name: data_element.name.clone(),
+                hash: self.hash.clone(),
+                sensitivity: data_element.sensitivity.clone(),
+                language: self.language.clone(),
+                code_segment: self.code_segment.clone(),
+                absolute_file_path: self.absolute_file_path.clone(),
+                relative_file_path: self.relative_file_path.clone(),
+                line_start: self.line,
+                line_end: self.line,
+                column_start: self.column,
+                column_end: self.column,
+                url_link: get_url_link(
+                    &config.repository.git_provider,
+                    &config.repository.base_url,
+                    &config.repository.commit,
+                    &self.relative_file_path,
+                    self.line,
+                    self.line,
+                ),
+                source: data_element.source.clone(),
+                tags: data_element.tags.clone(),
+            })
+            .collect()
     }
 }
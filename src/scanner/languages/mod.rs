@@ -4,5 +4,6 @@ pub mod python;
 pub mod typescript;
 
 pub use base::BaseScanner;
+pub use graphql::GraphQLScanner;
 pub use python::PythonScanner;
 pub use typescript::TypescriptScanner;
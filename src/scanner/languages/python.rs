@@ -1,16 +1,148 @@
+use std::collections::HashSet;
+
 use anyhow::Result;
 use tree_sitter::Node;
 
 use crate::enums::VisitChildren;
 use crate::scanner::languages::BaseScanner;
 use crate::scanner::common::{
-    get_child_by_field, get_children, get_children_by_field,
+    get_child_by_field, get_children, get_children_by_field, unescape_string_literal,
 };
+use crate::scanner::taint::CallSite;
 use crate::structs::{DataElementOccurrence, Vulnerability};
 use crate::structs::FileScanContext;
 
 pub struct PythonScanner;
 
+/// The data elements carried by an expression: a direct data-element match, a variable already
+/// tainted by an earlier assignment (visible anywhere in the enclosing scope chain), or (for a
+/// call) the union of whatever its arguments carry — a conservative stand-in for modeling the
+/// callee's actual return taint. Also recurses into the shapes that commonly wrap a tainted value
+/// at a call site: keyword arguments, attribute/subscript access, and f-string interpolations.
+fn extract_tainted_element_ids(ctx: &mut FileScanContext, node: &Node) -> HashSet<String> {
+    match node.kind() {
+        // e.g. `user.email` or `data["ssn"]` — match the full expression text directly, and also
+        // walk into the object being accessed in case it (rather than the whole expression) is
+        // what's tainted, e.g. a subscript on a variable tainted by an earlier assignment.
+        "identifier" | "attribute" | "subscript" => {
+            let text = ctx.get_node_text(node);
+            let mut element_ids: HashSet<String> = ctx
+                .find_data_element(&text)
+                .into_iter()
+                .flatten()
+                .map(|elem| elem.id.clone())
+                .collect();
+            if let Some(variable_elements) = ctx.lookup_tainted_variable(&text) {
+                element_ids.extend(variable_elements);
+            }
+            if let Some(value_node) =
+                node.child_by_field_name("value").or_else(|| node.child_by_field_name("object"))
+            {
+                element_ids.extend(extract_tainted_element_ids(ctx, &value_node));
+            }
+            element_ids
+        }
+        "call" => node
+            .child_by_field_name("arguments")
+            .map(|arguments| {
+                get_children(&arguments).into_iter().filter(|arg| arg.is_named()).fold(
+                    HashSet::new(),
+                    |mut element_ids, arg| {
+                        element_ids.extend(extract_tainted_element_ids(ctx, &arg));
+                        element_ids
+                    },
+                )
+            })
+            .unwrap_or_default(),
+        // e.g. `send(to=customer.email)` — the value carries taint, not the keyword name.
+        "keyword_argument" => node
+            .child_by_field_name("value")
+            .map(|value| extract_tainted_element_ids(ctx, &value))
+            .unwrap_or_default(),
+        // e.g. `logger.info(f"user {user.email}")` — walk each `{...}` interpolation, and also
+        // match the decoded literal text in case a hardcoded secret is embedded directly.
+        "string" => {
+            let mut element_ids =
+                get_children(node).into_iter().filter(|child| child.kind() == "interpolation").fold(
+                    HashSet::new(),
+                    |mut element_ids, child| {
+                        element_ids.extend(extract_tainted_element_ids(ctx, &child));
+                        element_ids
+                    },
+                );
+            let decoded = unescape_string_literal(&ctx.get_node_text(node));
+            element_ids.extend(
+                ctx.find_data_element(&decoded).into_iter().flatten().map(|elem| elem.id.clone()),
+            );
+            element_ids
+        }
+        "interpolation" => get_children(node).into_iter().filter(|child| child.is_named()).fold(
+            HashSet::new(),
+            |mut element_ids, child| {
+                element_ids.extend(extract_tainted_element_ids(ctx, &child));
+                element_ids
+            },
+        ),
+        _ => HashSet::new(),
+    }
+}
+
+/// Propagate taint from an assignment's right side onto its left side(s). Tuple/multiple-target
+/// assignments (`a, b = 1, 2`) distribute element sets positionally when the right side is also a
+/// tuple (parenthesized or bare) of the same length; otherwise (including a list literal or a
+/// shape mismatch) every target is conservatively tainted with everything the right side carries.
+fn assign_taint(ctx: &mut FileScanContext, left_node: &Node, right_node: &Node, augmented: bool) {
+    let targets: Vec<Node> = match left_node.kind() {
+        "pattern_list" | "tuple" | "expression_list" => {
+            get_children(left_node).into_iter().filter(|n| n.is_named()).collect()
+        }
+        _ => vec![*left_node],
+    };
+
+    let rhs_items: Option<Vec<Node>> = match right_node.kind() {
+        "expression_list" | "tuple" => {
+            Some(get_children(right_node).into_iter().filter(|n| n.is_named()).collect())
+        }
+        _ => None,
+    };
+
+    match rhs_items {
+        Some(items) if items.len() == targets.len() && targets.len() > 1 => {
+            for (target, value) in targets.iter().zip(items.iter()) {
+                let element_ids = extract_tainted_element_ids(ctx, value);
+                apply_taint(ctx, target, element_ids, augmented);
+            }
+        }
+        _ => {
+            let element_ids = extract_tainted_element_ids(ctx, right_node);
+            for target in &targets {
+                apply_taint(ctx, target, element_ids.clone(), augmented);
+            }
+        }
+    }
+}
+
+fn apply_taint(
+    ctx: &mut FileScanContext,
+    target: &Node,
+    element_ids: HashSet<String>,
+    augmented: bool,
+) {
+    if target.kind() != "identifier" {
+        return;
+    }
+    let name = ctx.get_node_text(target);
+    if augmented {
+        if !element_ids.is_empty() {
+            ctx.union_taint_variable(&name, element_ids);
+        }
+    } else {
+        // A plain reassignment severs whatever taint the variable carried before, even when the
+        // new value isn't tainted.
+        ctx.taint_variable(name, element_ids);
+    }
+}
+
 
 impl BaseScanner for PythonScanner {
     fn visit_node(ctx: &mut FileScanContext, node: &Node) -> Result<VisitChildren> {
@@ -25,6 +157,15 @@ impl BaseScanner for PythonScanner {
             // e.g. `def example_function():`
             "function_definition" => {
                 ctx.enter_function_scope(node);
+                // Register the function's parameters for interprocedural taint binding.
+                if let Some(params_node) = node.child_by_field_name("parameters") {
+                    let params = get_children(&params_node)
+                        .iter()
+                        .filter(|p| p.kind() == "identifier")
+                        .map(|p| ctx.get_node_text(p))
+                        .collect();
+                    ctx.record_taint_function(params);
+                }
             }
             // e.g. `lambda x: x + 1`
             "lambda" => {
@@ -48,6 +189,7 @@ impl BaseScanner for PythonScanner {
                         // e.g. request
                         let alias = ctx.get_node_text(&get_child_by_field(&child, "alias"));
                         // e.g. alias "urllib.request" to "request"
+                        ctx.record_taint_import(alias.clone(), module_name.clone());
                         ctx.put_alias(alias, module_name);
                     }
                 }
@@ -67,6 +209,10 @@ impl BaseScanner for PythonScanner {
                             let imported_obj_full_name =
                                 format!("{}.{}", module_name, imported_obj_name);
                             // e.g. alias "capture_exception" to "sentry_sdk.capture_exception"
+                            ctx.record_taint_import(
+                                imported_obj_name.clone(),
+                                imported_obj_full_name.clone(),
+                            );
                             ctx.put_alias(imported_obj_name, imported_obj_full_name);
                         }
                         // e.g. from sentry_sdk import capture_exception as capture
@@ -87,6 +233,19 @@ impl BaseScanner for PythonScanner {
                     }
                 }
             }
+            // Match against the decoded value of string literals so hardcoded secrets/PII
+            // obscured by escaping (e.g. `"foo\u{41}bar"`) are not missed.
+            "string" => {
+                let decoded = unescape_string_literal(&ctx.get_node_text(node));
+                if let Some(data_element) = ctx.find_data_element(&decoded) {
+                    let _ = ctx.put_occurrence(DataElementOccurrence::from_node(
+                        ctx,
+                        node,
+                        &data_element,
+                    ));
+                    return Ok(VisitChildren::No);
+                }
+            }
             "attribute" | "identifier" if node.end_byte() - node.start_byte() > 1 => {
                 let text = ctx.get_node_text(node);
                 if let Some(data_element) = ctx.find_data_element(&text) {
@@ -103,18 +262,35 @@ impl BaseScanner for PythonScanner {
                 let func_name = ctx.get_node_text(&func_node);
 
                 if let Some(data_sink) = ctx.find_data_sink(&func_name) {
+                    ctx.record_taint_sink(func_name.clone(), data_sink.id.clone());
+
                     let mut data_elements = vec![];
-                    for arg in get_children(&get_child_by_field(node, "arguments")) {
-                        match arg.kind() {
-                            "identifier" => {
-                                let arg_text = ctx.get_node_text(&arg);
-                                if let Some(elem) = ctx.find_data_element(&arg_text) {
-                                    data_elements.push(elem);
-                                }
+                    let mut arg_taint: Vec<HashSet<String>> = vec![];
+                    for arg in get_children(&get_child_by_field(node, "arguments"))
+                        .into_iter()
+                        .filter(|arg| arg.is_named())
+                    {
+                        let arg_elements = extract_tainted_element_ids(ctx, &arg);
+                        for id in &arg_elements {
+                            if let Some(elem) = ctx.config.data_elements.get(id) {
+                                data_elements.push(elem);
                             }
-                            _ => (),
                         }
+                        arg_taint.push(arg_elements);
                     }
+
+                    // Record the call site so taint arriving here (including through call
+                    // chains) is resolved to this sink in the interprocedural post-pass.
+                    let start = node.start_position();
+                    let end = node.end_position();
+                    ctx.record_taint_call(CallSite {
+                        callee: func_name.clone(),
+                        arg_taint,
+                        line_start: start.row + 1,
+                        line_end: end.row + 1,
+                        column_start: start.column + 1,
+                    });
+
                     if !data_elements.is_empty() {
                         let _ = ctx.put_vulnerability(Vulnerability::from_node(
                             ctx,
@@ -128,10 +304,15 @@ impl BaseScanner for PythonScanner {
             }
             "assignment" => {
                 let left_node = node.child_by_field_name("left").unwrap();
-                let left_node_text = ctx.get_node_text(&left_node);
-
                 if let Some(right_node) = node.child_by_field_name("right") {
-                    let right_node_text = ctx.get_node_text(&right_node);
+                    assign_taint(ctx, &left_node, &right_node, false);
+                }
+            }
+            // e.g. `total += user.balance`
+            "augmented_assignment" => {
+                let left_node = node.child_by_field_name("left").unwrap();
+                if let Some(right_node) = node.child_by_field_name("right") {
+                    assign_taint(ctx, &left_node, &right_node, true);
                 }
             }
             _ => (),
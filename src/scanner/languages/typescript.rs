@@ -4,6 +4,7 @@ use std::env::var;
 use tree_sitter::Node;
 
 use crate::enums::VisitChildren;
+use crate::scanner::common::unescape_string_literal;
 use crate::scanner::languages::base::BaseScanner;
 use crate::structs::{DataElement, DataElementOccurrence, FileScanContext, Vulnerability};
 
@@ -50,6 +51,21 @@ impl BaseScanner for TypescriptScanner {
                     }
                 }
             }
+            // Match against the decoded value of string literals so hardcoded secrets/PII
+            // obscured by escaping are not missed.
+            "string" | "template_string" => {
+                let decoded = unescape_string_literal(&state.get_node_text(node));
+                for data_elem in state.find_data_element(&decoded) {
+                    if let Some(data_element) = data_elem {
+                        let _ = state.put_occurrence(DataElementOccurrence::from_node(
+                            state,
+                            node,
+                            &data_element,
+                        ));
+                        return Ok(VisitChildren::No);
+                    }
+                }
+            }
             "method_definition" => {}
             "function_declaration" => {}
             "type_identifier" => {}
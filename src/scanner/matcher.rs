@@ -0,0 +1,192 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::structs::DataElement;
+
+/// A node in the Aho-Corasick trie: byte transitions, a failure link to the longest proper
+/// suffix that is also a trie prefix (the root fails to itself), and the keyword indices that
+/// terminate here or at any node reachable through the failure chain.
+#[derive(Debug)]
+struct TrieNode {
+    children: HashMap<u8, usize>,
+    fail: usize,
+    output: Vec<usize>,
+    /// Byte length of the keyword terminating at this node, if any; used to recover the match
+    /// span (and therefore its word boundaries) without re-scanning the text.
+    depth: usize,
+}
+
+impl TrieNode {
+    fn new(depth: usize) -> TrieNode {
+        TrieNode { children: HashMap::new(), fail: 0, output: Vec::new(), depth }
+    }
+}
+
+/// A shared, precompiled multi-pattern automaton over every data element's normalized name,
+/// built once per scan (not per file) and reused across every file's [`FileScanContext`]. Lets
+/// identifier/property/call-argument lookups resolve against the full data-element keyword set
+/// in a single O(len + matches) pass instead of probing each data element in turn.
+///
+/// Matching is case-insensitive, and a keyword only counts as matched when it sits on a word
+/// boundary: the characters immediately before and after it, if any, are not alphanumeric.
+/// Normalization maps every other character to `_`, so `_` separates words (`user_email`
+/// contains `email`; `emailed` does not).
+#[derive(Debug)]
+pub struct DataElementMatcher {
+    nodes: Vec<TrieNode>,
+    keyword_owners: Vec<String>,
+}
+
+fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+impl DataElementMatcher {
+    /// Build the trie from every data element's normalized `name`, then compute failure links
+    /// with a breadth-first pass so each node's failure pointer is resolved before it's used to
+    /// resolve a descendant's.
+    pub fn build(data_elements: &HashMap<String, DataElement>) -> DataElementMatcher {
+        let mut nodes = vec![TrieNode::new(0)];
+        let mut keyword_owners = Vec::new();
+
+        for data_element in data_elements.values() {
+            let keyword = normalize(&data_element.name);
+            if keyword.is_empty() {
+                continue;
+            }
+            let keyword_index = keyword_owners.len();
+            keyword_owners.push(data_element.id.clone());
+
+            let mut node = 0;
+            for (depth, byte) in keyword.bytes().enumerate() {
+                node = match nodes[node].children.get(&byte) {
+                    Some(&child) => child,
+                    None => {
+                        nodes.push(TrieNode::new(depth + 1));
+                        let child = nodes.len() - 1;
+                        nodes[node].children.insert(byte, child);
+                        child
+                    }
+                };
+            }
+            nodes[node].output.push(keyword_index);
+        }
+
+        build_failure_links(&mut nodes);
+        DataElementMatcher { nodes, keyword_owners }
+    }
+
+    /// Return the ids of every data element whose normalized name appears in `text` on a word
+    /// boundary, deduplicated, in a single pass over `text`'s bytes.
+    pub fn find_matches(&self, text: &str) -> Vec<&str> {
+        let normalized = normalize(text);
+        let bytes = normalized.as_bytes();
+        let mut node = 0;
+        let mut matched_keywords = Vec::new();
+
+        for (i, &byte) in bytes.iter().enumerate() {
+            while node != 0 && !self.nodes[node].children.contains_key(&byte) {
+                node = self.nodes[node].fail;
+            }
+            node = self.nodes[node].children.get(&byte).copied().unwrap_or(0);
+
+            for &keyword_index in &self.nodes[node].output {
+                let depth = self.nodes[node].depth;
+                let start = i + 1 - depth;
+                let end = i + 1;
+                let left_ok = is_boundary_byte(start.checked_sub(1).map(|i| bytes[i]));
+                let right_ok = is_boundary_byte(bytes.get(end).copied());
+                if left_ok && right_ok {
+                    matched_keywords.push(keyword_index);
+                }
+            }
+        }
+
+        matched_keywords.sort_unstable();
+        matched_keywords.dedup();
+        matched_keywords.into_iter().map(|index| self.keyword_owners[index].as_str()).collect()
+    }
+}
+
+/// A match edge is a word boundary unless the single neighboring byte on that side (if any)
+/// is alphanumeric; `_` (the normalized separator) and `None` (the start/end of `bytes`) count
+/// as a boundary.
+fn is_boundary_byte(byte: Option<u8>) -> bool {
+    match byte {
+        Some(b) if b.is_ascii_alphanumeric() => false,
+        _ => true,
+    }
+}
+
+fn build_failure_links(nodes: &mut [TrieNode]) {
+    let mut queue = VecDeque::new();
+    let root_children: Vec<(u8, usize)> = nodes[0].children.iter().map(|(&b, &n)| (b, n)).collect();
+    for (_, child) in root_children {
+        nodes[child].fail = 0;
+        queue.push_back(child);
+    }
+
+    while let Some(current) = queue.pop_front() {
+        let children: Vec<(u8, usize)> =
+            nodes[current].children.iter().map(|(&b, &n)| (b, n)).collect();
+        for (byte, child) in children {
+            let mut fail = nodes[current].fail;
+            while fail != 0 && !nodes[fail].children.contains_key(&byte) {
+                fail = nodes[fail].fail;
+            }
+            nodes[child].fail = nodes[fail].children.get(&byte).copied().unwrap_or(0);
+            if nodes[child].fail == child {
+                nodes[child].fail = 0;
+            }
+            let fail_output = nodes[nodes[child].fail].output.clone();
+            nodes[child].output.extend(fail_output);
+            queue.push_back(child);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::{Sensitivity, Source};
+
+    fn data_element(id: &str, name: &str) -> DataElement {
+        DataElement {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            include_patterns: vec![],
+            exclude_patterns: vec![],
+            is_enabled: true,
+            sensitivity: Sensitivity::Critical,
+            source: Source::HoundDog,
+            tags: vec![],
+            validator: None,
+        }
+    }
+
+    fn build(names: &[&str]) -> DataElementMatcher {
+        let data_elements = names
+            .iter()
+            .map(|name| (name.to_string(), data_element(name, name)))
+            .collect();
+        DataElementMatcher::build(&data_elements)
+    }
+
+    #[test]
+    fn finds_keyword_on_a_word_boundary() {
+        assert!(!build(&["email"]).find_matches("user_email").is_empty());
+    }
+
+    #[test]
+    fn finds_keyword_matching_the_whole_text() {
+        assert!(!build(&["email"]).find_matches("email").is_empty());
+    }
+
+    #[test]
+    fn does_not_match_inside_a_longer_word() {
+        assert!(build(&["email"]).find_matches("emailed").is_empty());
+    }
+}
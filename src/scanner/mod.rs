@@ -1,4 +1,6 @@
+use std::collections::HashSet;
 use std::env;
+use std::path::PathBuf;
 
 use ::tree_sitter::{Language, Parser};
 use anyhow::Result;
@@ -7,40 +9,254 @@ use tree_sitter_typescript::language_typescript;
 
 use database::ScanDatabase;
 use languages::base::BaseScanner;
-use languages::{PythonScanner, TypescriptScanner};
+use languages::{GraphQLScanner, PythonScanner, TypescriptScanner};
+use monorepo::{EmptyDiffMode, TargetGraph, UnmatchedPathMode};
+use taint::{TaintEngine, TaintPath};
 
-use crate::structs::{ScanConfig, ScanResults};
-use crate::utils::file::get_files_in_dir;
+use crate::enums::{Sensitivity, Severity};
+use crate::structs::{ScanConfig, Vulnerability, ScanResults};
+use crate::utils::file::{get_file_language, get_files_in_dir};
+use crate::utils::git::{
+    get_git_diff_files, get_git_diff_line_ranges, get_url_link, intersects_changed_lines,
+};
+use crate::utils::hash::calculate_vulnerability_fingerprint;
 
 pub mod common;
 pub mod database;
+#[cfg(test)]
+pub mod fixtures;
 pub mod languages;
+pub mod matcher;
+pub mod monorepo;
+pub mod search;
+pub mod taint;
 
 pub fn run_scan(config: &ScanConfig) -> Result<ScanResults> {
     let database = initialize_database();
     let mut py_parser = initialize_parser(language_python());
     let mut ts_parser = initialize_parser(language_typescript());
 
-    for file in get_files_in_dir(&config.repository.path) {
+    for file in files_to_scan(config)? {
         let _ = match file.extension().unwrap_or_default().to_str().unwrap() {
             "py" => PythonScanner::scan_file(&database, config, &mut py_parser, &file),
             "js" | "jsx" | "ts" | "tsx" => {
                 TypescriptScanner::scan_file(&database, config, &mut ts_parser, &file)
             }
+            "gql" | "graphql" => GraphQLScanner::scan_file(&database, config, &file),
             _ => Ok(()),
         };
     }
-    let vulnerabilities = database.get_vulnerabilities()?;
-    let occurrences = database.get_data_element_occurrences()?;
+    // Resolve interprocedural taint flows collected during scanning into cross-file
+    // vulnerabilities whose code segment traces the function→…→sink call path the taint took.
+    {
+        let collector = database.taint();
+        let engine = TaintEngine::new(&collector);
+        for path in engine.resolve() {
+            if let Some(vulnerability) = build_taint_vulnerability(config, &path) {
+                database.put_vulnerability(&vulnerability)?;
+            }
+        }
+    }
+
+    let mut vulnerabilities = database.get_vulnerabilities()?;
+    let mut occurrences = database.get_data_element_occurrences()?;
+    occurrences.extend(
+        database.get_graphql_findings()?.iter().flat_map(|finding| finding.to_occurrences(config)),
+    );
+
+    // Narrow findings down to the lines the diff actually touched, so a PR/CI run against a
+    // baseline reports only newly-introduced leaks instead of every pre-existing one in a
+    // changed file.
+    if let Some(baseline) = config.diff_baseline.as_deref() {
+        let repo = git2::Repository::open(&config.repository.path)?;
+        let changed_ranges = get_git_diff_line_ranges(&repo, baseline)?;
+        vulnerabilities.retain(|vulnerability| {
+            intersects_changed_lines(
+                &changed_ranges,
+                &vulnerability.relative_file_path,
+                vulnerability.line_start,
+                vulnerability.line_end,
+            )
+        });
+        occurrences.retain(|occurrence| {
+            intersects_changed_lines(
+                &changed_ranges,
+                &occurrence.relative_file_path,
+                occurrence.line_start,
+                occurrence.line_end,
+            )
+        });
+    }
+
+    // `--include-severity` filtering and the `exceeds_fail_severity_threshold` check both live in
+    // `ScanResults::new` now, so the latter always sees the unfiltered set (see its doc comment).
     Ok(ScanResults::new(config, vulnerabilities, occurrences))
 }
 
-fn initialize_parser(language: Language) -> Parser {
+/// Project a resolved [`TaintPath`] onto a [`Vulnerability`], looking up sink metadata and
+/// deriving severity from the sensitivity of the tainted data elements.
+pub(crate) fn build_taint_vulnerability(
+    config: &ScanConfig,
+    path: &TaintPath,
+) -> Option<Vulnerability> {
+    let language = get_file_language(std::path::Path::new(&path.relative_file_path))?;
+    let data_sink = config.data_sinks.get(&language)?.get(&path.data_sink_id)?;
+
+    let data_elements: Vec<_> =
+        path.data_element_ids.iter().filter_map(|id| config.data_elements.get(id)).collect();
+    if data_elements.is_empty() {
+        return None;
+    }
+    let mut severity = data_elements
+        .iter()
+        .map(|elem| &elem.sensitivity)
+        .min()
+        .map(|s| match s {
+            Sensitivity::Critical => Severity::Critical,
+            Sensitivity::Medium => Severity::Medium,
+            Sensitivity::Low => Severity::Low,
+        })
+        .unwrap();
+
+    let code_segment = path.trail.join(" -> ");
+
+    // Honor sanitizers across the resolved call chain: if any hop matches a sanitizer for
+    // one of the tainted elements' sources, downgrade the finding and record it.
+    let sources: std::collections::HashSet<_> =
+        data_elements.iter().map(|elem| &elem.source).collect();
+    let sanitized_by = config
+        .sanitizers
+        .iter()
+        .find(|sanitizer| {
+            sources.contains(&sanitizer.source) && sanitizer.pattern.is_match(&code_segment)
+        })
+        .map(|sanitizer| sanitizer.sanitizer_type.clone());
+    if sanitized_by.is_some() {
+        severity = severity.downgraded();
+    }
+    Some(Vulnerability {
+        data_sink_id: path.data_sink_id.clone(),
+        data_element_ids: path.data_element_ids.clone(),
+        data_element_names: data_elements.iter().map(|elem| elem.name.clone()).collect(),
+        hash: calculate_vulnerability_fingerprint(
+            &path.data_sink_id,
+            &path.data_element_ids,
+            &path.relative_file_path,
+            &code_segment,
+        ),
+        description: data_sink.description.clone(),
+        severity,
+        language,
+        code_segment,
+        absolute_file_path: config
+            .repository
+            .path
+            .join(&path.relative_file_path)
+            .display()
+            .to_string(),
+        relative_file_path: path.relative_file_path.clone(),
+        line_start: path.line_start,
+        line_end: path.line_end,
+        column_start: path.column_start,
+        column_end: path.column_start,
+        url_link: get_url_link(
+            &config.repository.base_url,
+            &config.repository.commit,
+            &path.relative_file_path,
+            &config.repository.git_provider,
+            path.line_start,
+            path.line_end,
+            path.column_start,
+        ),
+        cwe: data_sink.cwe.clone(),
+        owasp: data_sink.owasp.clone(),
+        sanitized_by,
+        // Taint findings span a synthesized call trail rather than a contiguous source range,
+        // so there is no single window to frame.
+        code_frame: None,
+    })
+}
+
+/// The files to feed to the language scanners: every file under the repository root, unless a
+/// Git diff baseline narrows it down. With declared monorepo targets, the baseline narrows the
+/// scan to whichever targets the diff actually touches (see [`resolve_scan_roots`]); without
+/// any, it narrows the scan to exactly the changed files (see [`files_changed_since`]).
+fn files_to_scan(config: &ScanConfig) -> Result<Vec<PathBuf>> {
+    if let Some(baseline) = config.diff_baseline.as_deref() {
+        if config.targets.is_empty() {
+            return files_changed_since(config, baseline);
+        }
+    }
+    let include = config.include_globs.as_ref();
+    let exclude = config.exclude_globs.as_ref();
+    Ok(match resolve_scan_roots(config)? {
+        Some(roots) => {
+            roots.iter().flat_map(|root| get_files_in_dir(root, include, exclude)).collect()
+        }
+        None => get_files_in_dir(&config.repository.path, include, exclude).collect(),
+    })
+}
+
+/// Restrict the scan to exactly the files a Git diff against `baseline` touched, for repos with
+/// no declared monorepo targets. Renamed files are scanned at their new path (`get_git_diff_files`
+/// already resolves renames to `new_file().path()`); deleted files are skipped since there's
+/// nothing left to scan. Intersected with [`get_files_in_dir`] so `.hounddogignore` and the usual
+/// walk filters still apply.
+fn files_changed_since(config: &ScanConfig, baseline: &str) -> Result<Vec<PathBuf>> {
+    let repo = git2::Repository::open(&config.repository.path)?;
+    let changed_paths: HashSet<PathBuf> =
+        get_git_diff_files(&repo, Some(baseline))?.into_iter().collect();
+
+    Ok(get_files_in_dir(
+        &config.repository.path,
+        config.include_globs.as_ref(),
+        config.exclude_globs.as_ref(),
+    )
+        .filter(|file| {
+            file.strip_prefix(&config.repository.path)
+                .map(|relative_path| changed_paths.contains(relative_path))
+                .unwrap_or(false)
+        })
+        .collect())
+}
+
+/// Resolve the scan roots implied by `config`'s diff baseline and declared targets.
+///
+/// Returns `Ok(None)` when the whole repository should be scanned (no baseline, no declared
+/// targets, an empty diff under [`EmptyDiffMode::ScanAll`], or a changed path matching no
+/// target under [`UnmatchedPathMode::ScanAll`]). Returns `Ok(Some(roots))` — possibly empty —
+/// otherwise.
+fn resolve_scan_roots(config: &ScanConfig) -> Result<Option<Vec<PathBuf>>> {
+    let Some(baseline) = config.diff_baseline.as_deref() else {
+        return Ok(None);
+    };
+    if config.targets.is_empty() {
+        return Ok(None);
+    }
+
+    let repo = git2::Repository::open(&config.repository.path)?;
+    let changed_paths = get_git_diff_files(&repo, Some(baseline))?;
+    if changed_paths.is_empty() {
+        return Ok(match config.empty_diff_mode {
+            EmptyDiffMode::ScanAll => None,
+            EmptyDiffMode::ScanNothing => Some(vec![]),
+        });
+    }
+
+    let graph = TargetGraph::new(config.targets.clone());
+    let affected = graph.affected_targets(&changed_paths);
+    if affected.has_unmatched && config.unmatched_path_mode == UnmatchedPathMode::ScanAll {
+        return Ok(None);
+    }
+    Ok(Some(graph.roots(&affected.names, &config.repository.path)))
+}
+
+pub(crate) fn initialize_parser(language: Language) -> Parser {
     let mut parser = Parser::new();
     parser.set_language(&language).unwrap();
     parser
 }
 
-fn initialize_database() -> ScanDatabase {
+pub(crate) fn initialize_database() -> ScanDatabase {
     ScanDatabase::new(env::temp_dir().join("hounddog.db").as_path())
 }
@@ -0,0 +1,111 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// A monorepo target: a directory prefix owning a set of files, plus the names of the other
+/// targets it depends on. A change in a depended-on target re-scans its dependents.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Target {
+    pub name: String,
+    pub path: PathBuf,
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+}
+
+/// What to scan when the diff against the baseline is empty — made explicit so an empty diff
+/// is never silently interpreted as "scan everything" (or vice versa).
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum EmptyDiffMode {
+    #[default]
+    ScanNothing,
+    ScanAll,
+}
+
+/// How to treat a changed path that falls under no declared target.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum UnmatchedPathMode {
+    #[default]
+    ScanAll,
+    Ignore,
+}
+
+/// The target roots affected by a diff, plus whether any changed path matched no target.
+pub struct AffectedTargets {
+    pub names: HashSet<String>,
+    pub has_unmatched: bool,
+}
+
+/// The declared targets plus the reverse dependency DAG, used to resolve which targets a set of
+/// changed paths affects. A path's owning target is found by a linear scan over every target root
+/// for the longest matching prefix; no prefix trie is built.
+pub struct TargetGraph {
+    targets: Vec<Target>,
+    /// Reverse edges: target name -> names of targets that depend on it.
+    dependents: HashMap<String, Vec<String>>,
+}
+
+impl TargetGraph {
+    pub fn new(targets: Vec<Target>) -> TargetGraph {
+        let mut dependents: HashMap<String, Vec<String>> = HashMap::new();
+        for target in &targets {
+            for dependency in &target.depends_on {
+                dependents.entry(dependency.clone()).or_default().push(target.name.clone());
+            }
+        }
+        TargetGraph { targets, dependents }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    /// The target owning `path`, resolved as the longest matching root prefix.
+    pub fn owning_target(&self, path: &Path) -> Option<&Target> {
+        self.targets
+            .iter()
+            .filter(|target| path.starts_with(&target.path))
+            .max_by_key(|target| target.path.components().count())
+    }
+
+    /// Resolve changed paths to their owning targets, then expand along reverse dependency
+    /// edges so a change in a shared target also re-scans everything that depends on it.
+    pub fn affected_targets(&self, changed_paths: &[PathBuf]) -> AffectedTargets {
+        let mut queue: VecDeque<String> = VecDeque::new();
+        let mut names: HashSet<String> = HashSet::new();
+        let mut has_unmatched = false;
+
+        for path in changed_paths {
+            match self.owning_target(path) {
+                Some(target) => {
+                    if names.insert(target.name.clone()) {
+                        queue.push_back(target.name.clone());
+                    }
+                }
+                None => has_unmatched = true,
+            }
+        }
+
+        while let Some(name) = queue.pop_front() {
+            for dependent in self.dependents.get(&name).into_iter().flatten() {
+                if names.insert(dependent.clone()) {
+                    queue.push_back(dependent.clone());
+                }
+            }
+        }
+
+        AffectedTargets { names, has_unmatched }
+    }
+
+    /// The roots of the given affected targets, each joined onto `repo_root`.
+    pub fn roots<'a>(&'a self, names: &'a HashSet<String>, repo_root: &Path) -> Vec<PathBuf> {
+        self.targets
+            .iter()
+            .filter(|target| names.contains(&target.name))
+            .map(|target| repo_root.join(&target.path))
+            .collect()
+    }
+}
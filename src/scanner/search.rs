@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+
+use crate::enums::{Language, Sensitivity, Severity};
+use crate::structs::ScanResults;
+
+/// The kind of finding a [`SearchDoc`] was built from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FindingKind {
+    Occurrence,
+    Vulnerability,
+}
+
+/// A single indexed finding, carrying its free-text tokens and facet values.
+#[derive(Debug)]
+pub struct SearchDoc {
+    pub kind: FindingKind,
+    pub index: usize,
+    pub relative_file_path: String,
+    pub language: Language,
+    pub sensitivity: Option<Sensitivity>,
+    pub severity: Option<Severity>,
+    pub cwe: Vec<String>,
+    pub owasp: Vec<String>,
+    pub tags: Vec<String>,
+    tokens: Vec<String>,
+}
+
+/// A faceted query over the index.
+#[derive(Debug, Default)]
+pub struct SearchQuery {
+    pub text: String,
+    pub sensitivity: Option<Sensitivity>,
+    pub severity: Option<Severity>,
+    pub language: Option<Language>,
+    pub cwe: Vec<String>,
+    pub owasp: Vec<String>,
+    pub tags: Vec<String>,
+    pub path_prefix: Option<String>,
+}
+
+/// Counts of each facet value across a result set, for drill-down UIs.
+#[derive(Debug, Default)]
+pub struct Facets {
+    pub sensitivity: HashMap<String, usize>,
+    pub severity: HashMap<String, usize>,
+    pub language: HashMap<String, usize>,
+    pub cwe: HashMap<String, usize>,
+    pub owasp: HashMap<String, usize>,
+    pub tags: HashMap<String, usize>,
+}
+
+/// An in-memory, typo-tolerant inverted index over scan findings.
+pub struct SearchIndex {
+    docs: Vec<SearchDoc>,
+    postings: HashMap<String, Vec<usize>>,
+}
+
+impl SearchIndex {
+    /// Build the index from a completed scan's occurrences and vulnerabilities.
+    pub fn build(results: &ScanResults) -> SearchIndex {
+        let mut docs = Vec::new();
+
+        for (index, occurrence) in results.occurrences.iter().enumerate() {
+            docs.push(SearchDoc {
+                kind: FindingKind::Occurrence,
+                index,
+                relative_file_path: occurrence.relative_file_path.clone(),
+                language: occurrence.language.clone(),
+                sensitivity: Some(occurrence.sensitivity.clone()),
+                severity: None,
+                cwe: vec![],
+                owasp: vec![],
+                tags: occurrence.tags.clone(),
+                tokens: tokenize_all(&[
+                    &occurrence.data_element_name,
+                    &occurrence.code_segment,
+                    &occurrence.relative_file_path,
+                ]),
+            });
+        }
+        for (index, vulnerability) in results.vulnerabilities.iter().enumerate() {
+            docs.push(SearchDoc {
+                kind: FindingKind::Vulnerability,
+                index,
+                relative_file_path: vulnerability.relative_file_path.clone(),
+                language: vulnerability.language.clone(),
+                sensitivity: None,
+                severity: Some(vulnerability.severity.clone()),
+                cwe: vulnerability.cwe.clone(),
+                owasp: vulnerability.owasp.clone(),
+                tags: vec![],
+                tokens: tokenize_all(&[
+                    &vulnerability.description,
+                    &vulnerability.code_segment,
+                    &vulnerability.relative_file_path,
+                    &vulnerability.data_element_names.join(" "),
+                ]),
+            });
+        }
+
+        let mut postings: HashMap<String, Vec<usize>> = HashMap::new();
+        for (doc_id, doc) in docs.iter().enumerate() {
+            for token in &doc.tokens {
+                let entry = postings.entry(token.clone()).or_default();
+                if entry.last() != Some(&doc_id) {
+                    entry.push(doc_id);
+                }
+            }
+        }
+
+        SearchIndex { docs, postings }
+    }
+
+    /// Run a faceted, typo-tolerant query and return matching docs ranked by relevance.
+    pub fn search(&self, query: &SearchQuery) -> Vec<&SearchDoc> {
+        let mut query_terms = tokenize(&query.text);
+        query_terms.sort();
+        query_terms.dedup();
+
+        // Score by the number of distinct query terms a doc matched.
+        let mut scores: HashMap<usize, usize> = HashMap::new();
+        if query_terms.is_empty() {
+            // No free text: every doc that passes the facet filters is a candidate.
+            for doc_id in 0..self.docs.len() {
+                scores.insert(doc_id, 0);
+            }
+        } else {
+            for term in &query_terms {
+                for doc_id in self.candidate_docs(term) {
+                    *scores.entry(doc_id).or_default() += 1;
+                }
+            }
+        }
+
+        let mut matches: Vec<(usize, &SearchDoc)> = scores
+            .iter()
+            .map(|(&doc_id, &score)| (score, &self.docs[doc_id]))
+            .filter(|(_, doc)| self.matches_facets(doc, query))
+            .collect();
+
+        matches.sort_by(|(score_a, a), (score_b, b)| {
+            score_b
+                .cmp(score_a)
+                .then_with(|| severity_rank(b).cmp(&severity_rank(a)))
+                .then_with(|| a.relative_file_path.cmp(&b.relative_file_path))
+        });
+        matches.into_iter().map(|(_, doc)| doc).collect()
+    }
+
+    /// Compute facet counts over a result set so consumers can drill down.
+    pub fn facets(&self, docs: &[&SearchDoc]) -> Facets {
+        let mut facets = Facets::default();
+        for doc in docs {
+            if let Some(s) = &doc.sensitivity {
+                *facets.sensitivity.entry(s.to_string()).or_default() += 1;
+            }
+            if let Some(s) = &doc.severity {
+                *facets.severity.entry(s.to_string()).or_default() += 1;
+            }
+            *facets.language.entry(doc.language.to_string()).or_default() += 1;
+            for cwe in &doc.cwe {
+                *facets.cwe.entry(cwe.clone()).or_default() += 1;
+            }
+            for owasp in &doc.owasp {
+                *facets.owasp.entry(owasp.clone()).or_default() += 1;
+            }
+            for tag in &doc.tags {
+                *facets.tags.entry(tag.clone()).or_default() += 1;
+            }
+        }
+        facets
+    }
+
+    /// Tokens matching a query term: exact, prefix, or within Levenshtein distance ≤2.
+    fn candidate_docs(&self, term: &str) -> Vec<usize> {
+        let mut docs = Vec::new();
+        for (token, postings) in &self.postings {
+            if token == term
+                || token.starts_with(term)
+                || (term.len() >= 4 && token.len() >= 4 && bounded_levenshtein(term, token, 2) <= 2)
+            {
+                docs.extend_from_slice(postings);
+            }
+        }
+        docs.sort_unstable();
+        docs.dedup();
+        docs
+    }
+
+    fn matches_facets(&self, doc: &SearchDoc, query: &SearchQuery) -> bool {
+        if let Some(s) = &query.sensitivity {
+            if doc.sensitivity.as_ref() != Some(s) {
+                return false;
+            }
+        }
+        if let Some(s) = &query.severity {
+            if doc.severity.as_ref() != Some(s) {
+                return false;
+            }
+        }
+        if let Some(lang) = &query.language {
+            if &doc.language != lang {
+                return false;
+            }
+        }
+        if !query.cwe.is_empty() && !query.cwe.iter().any(|c| doc.cwe.contains(c)) {
+            return false;
+        }
+        if !query.owasp.is_empty() && !query.owasp.iter().any(|o| doc.owasp.contains(o)) {
+            return false;
+        }
+        if !query.tags.is_empty() && !query.tags.iter().any(|t| doc.tags.contains(t)) {
+            return false;
+        }
+        if let Some(prefix) = &query.path_prefix {
+            if !doc.relative_file_path.starts_with(prefix) {
+                return false;
+            }
+        }
+        true
+    }
+
+}
+
+/// Rank a doc's severity/sensitivity so Critical findings float to the top.
+fn severity_rank(doc: &SearchDoc) -> u8 {
+    match doc.severity {
+        Some(Severity::Critical) => 3,
+        Some(Severity::Medium) => 2,
+        Some(Severity::Low) => 1,
+        None => match doc.sensitivity {
+            Some(Sensitivity::Critical) => 3,
+            Some(Sensitivity::Medium) => 2,
+            Some(Sensitivity::Low) => 1,
+            None => 0,
+        },
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_lowercase())
+        .collect()
+}
+
+fn tokenize_all(fields: &[&str]) -> Vec<String> {
+    let mut tokens: Vec<String> = fields.iter().flat_map(|f| tokenize(f)).collect();
+    tokens.sort();
+    tokens.dedup();
+    tokens
+}
+
+/// Bounded edit-distance DP: returns early with `max + 1` once the best row exceeds `max`.
+fn bounded_levenshtein(a: &str, b: &str, max: usize) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.len().abs_diff(b.len()) > max {
+        return max + 1;
+    }
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        curr[0] = i;
+        let mut row_min = curr[0];
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+            row_min = row_min.min(curr[j]);
+        }
+        if row_min > max {
+            return max + 1;
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
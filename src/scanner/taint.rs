@@ -0,0 +1,221 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Fully-qualified identity of a function/method definition across the repository.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FunctionId {
+    pub relative_file_path: String,
+    pub qualified_name: String,
+}
+
+/// A call observed inside a function body, recorded during per-file scanning.
+#[derive(Clone, Debug)]
+pub struct CallSite {
+    /// The name as written at the call site (may be an import alias).
+    pub callee: String,
+    /// Per-argument data-element taint, positionally aligned with the call's arguments.
+    pub arg_taint: Vec<HashSet<String>>,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub column_start: usize,
+}
+
+/// A per-function summary harvested during scanning and resolved afterwards.
+#[derive(Clone, Debug, Default)]
+pub struct FunctionSummary {
+    pub params: Vec<String>,
+    /// Data elements flowing out of the function via its return value.
+    pub returns: HashSet<String>,
+    pub calls: Vec<CallSite>,
+}
+
+/// One resolved taint flow from a sensitive data element to a sink, spanning call edges.
+#[derive(Clone, Debug)]
+pub struct TaintPath {
+    pub data_sink_id: String,
+    pub data_element_ids: Vec<String>,
+    pub relative_file_path: String,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub column_start: usize,
+    /// Human-readable `caller -> callee -> ... -> sink` trail for the code segment.
+    pub trail: Vec<String>,
+}
+
+/// Collects the raw facts needed for interprocedural taint analysis. Populated while
+/// scanning each file, then consumed once by [`TaintEngine::resolve`].
+#[derive(Debug, Default)]
+pub struct TaintCollector {
+    functions: HashMap<FunctionId, FunctionSummary>,
+    /// Per-file import maps: alias -> fully-qualified target.
+    imports: HashMap<String, HashMap<String, String>>,
+    /// Sinks matched during scanning, keyed by the name written at the call site.
+    sinks: HashMap<String, String>,
+}
+
+impl TaintCollector {
+    pub fn record_function(&mut self, id: FunctionId, summary: FunctionSummary) {
+        self.functions.insert(id, summary);
+    }
+
+    /// Append a call site to a function, creating the summary if the function is unknown.
+    pub fn push_call(&mut self, id: FunctionId, call: CallSite) {
+        self.functions.entry(id).or_default().calls.push(call);
+    }
+
+    pub fn record_import(&mut self, relative_file_path: &str, alias: String, target: String) {
+        self.imports.entry(relative_file_path.to_string()).or_default().insert(alias, target);
+    }
+
+    pub fn record_sink(&mut self, callee: String, data_sink_id: String) {
+        self.sinks.insert(callee, data_sink_id);
+    }
+}
+
+/// Resolves a [`TaintCollector`] into a call graph and propagates taint to sinks.
+pub struct TaintEngine<'a> {
+    collector: &'a TaintCollector,
+}
+
+impl<'a> TaintEngine<'a> {
+    pub fn new(collector: &'a TaintCollector) -> TaintEngine<'a> {
+        TaintEngine { collector }
+    }
+
+    /// Build the call graph and propagate data-element taint along it until it reaches a
+    /// sink, yielding one [`TaintPath`] per sink-reaching flow.
+    pub fn resolve(&self) -> Vec<TaintPath> {
+        // Index functions by their bare qualified name for call resolution through imports.
+        let by_name: HashMap<&str, &FunctionId> =
+            self.collector.functions.keys().map(|id| (id.qualified_name.as_str(), id)).collect();
+
+        let mut paths = Vec::new();
+
+        for (id, summary) in &self.collector.functions {
+            for call in &summary.calls {
+                let resolved = self.resolve_callee(&id.relative_file_path, &call.callee);
+
+                // Direct sink hit: a tainted argument reaches a known sink.
+                if let Some(data_sink_id) = self.collector.sinks.get(&resolved) {
+                    let elements = union(&call.arg_taint);
+                    if !elements.is_empty() {
+                        paths.push(TaintPath {
+                            data_sink_id: data_sink_id.clone(),
+                            data_element_ids: sorted(&elements),
+                            relative_file_path: id.relative_file_path.clone(),
+                            line_start: call.line_start,
+                            line_end: call.line_end,
+                            column_start: call.column_start,
+                            trail: vec![id.qualified_name.clone(), resolved.clone()],
+                        });
+                    }
+                    continue;
+                }
+
+                // Otherwise follow the call edge into the callee, binding tainted arguments
+                // to parameters and chasing transitively to a sink.
+                if let Some(&callee_id) = by_name.get(resolved.as_str()) {
+                    self.chase(callee_id, &by_name, call, id, &mut paths);
+                }
+            }
+        }
+        paths
+    }
+
+    /// Resolve a call-site name through the file's import map to a qualified target.
+    fn resolve_callee(&self, relative_file_path: &str, callee: &str) -> String {
+        self.collector
+            .imports
+            .get(relative_file_path)
+            .and_then(|map| map.get(callee))
+            .cloned()
+            .unwrap_or_else(|| callee.to_string())
+    }
+
+    /// Walk the call graph from `entry`, propagating the incoming call's argument taint into
+    /// matching parameters, until a sink is reached. Bounded by a visited set to stay finite.
+    fn chase(
+        &self,
+        entry: &FunctionId,
+        by_name: &HashMap<&str, &FunctionId>,
+        incoming: &CallSite,
+        origin: &FunctionId,
+        paths: &mut Vec<TaintPath>,
+    ) {
+        let mut visited: HashSet<&FunctionId> = HashSet::new();
+        let mut queue: VecDeque<(&FunctionId, HashMap<String, HashSet<String>>, Vec<String>)> =
+            VecDeque::new();
+
+        let entry_summary = &self.collector.functions[entry];
+        let tainted_params = bind_params(&entry_summary.params, &incoming.arg_taint);
+        queue.push_back((entry, tainted_params, vec![origin.qualified_name.clone()]));
+
+        while let Some((func_id, tainted, trail)) = queue.pop_front() {
+            if !visited.insert(func_id) {
+                continue;
+            }
+            let summary = &self.collector.functions[func_id];
+            let mut next_trail = trail.clone();
+            next_trail.push(func_id.qualified_name.clone());
+
+            for call in &summary.calls {
+                // Taint entering this call: its direct argument taint plus the taint of every
+                // tainted parameter of this function (which arguments use which parameters is
+                // not tracked).
+                let mut elements = union(&call.arg_taint);
+                for param_elements in tainted.values() {
+                    elements.extend(param_elements.iter().cloned());
+                }
+                if elements.is_empty() {
+                    continue;
+                }
+
+                let resolved = self.resolve_callee(&func_id.relative_file_path, &call.callee);
+                if let Some(data_sink_id) = self.collector.sinks.get(&resolved) {
+                    let mut trail = next_trail.clone();
+                    trail.push(resolved.clone());
+                    paths.push(TaintPath {
+                        data_sink_id: data_sink_id.clone(),
+                        data_element_ids: sorted(&elements),
+                        relative_file_path: func_id.relative_file_path.clone(),
+                        line_start: call.line_start,
+                        line_end: call.line_end,
+                        column_start: call.column_start,
+                        trail,
+                    });
+                } else if let Some(&callee_id) = by_name.get(resolved.as_str()) {
+                    let callee_summary = &self.collector.functions[callee_id];
+                    let bound = bind_scalar(&callee_summary.params, &elements);
+                    queue.push_back((callee_id, bound, next_trail.clone()));
+                }
+            }
+        }
+    }
+}
+
+fn bind_params(params: &[String], arg_taint: &[HashSet<String>]) -> HashMap<String, HashSet<String>> {
+    params
+        .iter()
+        .enumerate()
+        .filter_map(|(i, p)| arg_taint.get(i).map(|t| (p.clone(), t.clone())))
+        .filter(|(_, t)| !t.is_empty())
+        .collect()
+}
+
+fn bind_scalar(params: &[String], elements: &HashSet<String>) -> HashMap<String, HashSet<String>> {
+    // Conservative: when we can't align positions, taint every parameter.
+    params.iter().map(|p| (p.clone(), elements.clone())).collect()
+}
+
+fn union(sets: &[HashSet<String>]) -> HashSet<String> {
+    let mut out = HashSet::new();
+    for set in sets {
+        out.extend(set.iter().cloned());
+    }
+    out
+}
+
+fn sorted(set: &HashSet<String>) -> Vec<String> {
+    let mut v: Vec<String> = set.iter().cloned().collect();
+    v.sort();
+    v
+}
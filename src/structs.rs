@@ -4,18 +4,24 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use anyhow::Result;
+use globset::GlobSet;
 use indexmap::IndexMap;
-use regex::Regex;
+use regex::{Regex, RegexSet};
 use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 use tree_sitter::Node;
 
 use crate::enums::{GitProvider, Language, OutputFormat, ScopeType, Sensitivity, Severity, Source};
+use crate::scanner::common::{LineIndex, SuppressionIndex};
 use crate::scanner::database::ScanDatabase;
+use crate::scanner::matcher::DataElementMatcher;
+use crate::scanner::monorepo::{EmptyDiffMode, Target, UnmatchedPathMode};
+use crate::scanner::taint::{CallSite, FunctionId, FunctionSummary};
 use crate::utils::file::get_file_language;
 use crate::utils::git::get_url_link;
-use crate::utils::hash::calculate_md5_hash;
+use crate::utils::hash::{calculate_content_fingerprint, calculate_vulnerability_fingerprint};
 use crate::utils::serde::{deserialize_regex, deserialize_regex_option, deserialize_regex_vec};
+use crate::utils::validator::Validator;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -91,12 +97,25 @@ pub struct DataElement {
     pub sensitivity: Sensitivity,
     pub source: Source,
     pub tags: Vec<String>,
+    /// Optional structural check applied to the matched substring (Luhn, IBAN, wallet
+    /// checksum, entropy gate) to reject regex hits that cannot be the real thing.
+    #[serde(default)]
+    pub validator: Option<Validator>,
 }
 
 impl DataElement {
     pub fn is_match(&self, s: &str) -> bool {
-        self.include_patterns.iter().any(|p| p.is_match(s))
-            && !self.exclude_patterns.iter().any(|p| p.is_match(s))
+        let matched = match self.include_patterns.iter().find_map(|p| p.find(s)) {
+            Some(matched) => matched,
+            None => return false,
+        };
+        if self.exclude_patterns.iter().any(|p| p.is_match(s)) {
+            return false;
+        }
+        match &self.validator {
+            Some(validator) => validator.is_valid(matched.as_str()),
+            None => true,
+        }
     }
 }
 
@@ -215,14 +234,11 @@ impl DataElementOccurrence {
             data_element_id: data_element.id.clone(),
             data_element_name: data_element.name.clone(),
             sensitivity: data_element.sensitivity.clone(),
-            hash: calculate_md5_hash(format!(
-                "{}|{}|{}|{}|{}",
-                ctx.config.repository.name,
-                ctx.config.repository.branch,
-                data_element.id.clone(),
-                ctx.relative_file_path.display().to_string(),
-                ctx.get_node_text(node)
-            )),
+            hash: calculate_content_fingerprint(
+                &ctx.relative_file_path.display().to_string(),
+                line_start,
+                &ctx.get_node_text(node),
+            ),
             language: ctx.language.clone(),
             code_segment: ctx.get_code_line(node),
             absolute_file_path: ctx.absolute_file_path.display().to_string(),
@@ -246,6 +262,24 @@ impl DataElementOccurrence {
     }
 }
 
+/// A single line of a [`CodeFrame`], tagged with its absolute source line number.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub struct CodeFrameLine {
+    pub line: usize,
+    pub text: String,
+    /// True for the line containing the match, so reporters can highlight it.
+    pub is_match: bool,
+}
+
+/// A window of source lines around a finding — the match line plus a few lines of context on
+/// either side — with the match's column range, so reporters can render a caret/highlight.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub struct CodeFrame {
+    pub lines: Vec<CodeFrameLine>,
+    pub column_start: usize,
+    pub column_end: usize,
+}
+
 #[derive(Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
 pub struct Vulnerability {
     pub data_sink_id: String,
@@ -265,6 +299,13 @@ pub struct Vulnerability {
     pub url_link: String,
     pub cwe: Vec<String>,
     pub owasp: Vec<String>,
+    /// The sanitizer (by type) that was applied to the tainted value before the sink, if
+    /// any. When set, the finding's severity has already been lowered one level.
+    #[serde(default)]
+    pub sanitized_by: Option<String>,
+    /// A contextualized snippet (surrounding lines + highlighted match) for reporters.
+    #[serde(default)]
+    pub code_frame: Option<CodeFrame>,
 }
 
 impl Vulnerability {
@@ -281,29 +322,40 @@ impl Vulnerability {
         let column_start = start_pos.column + 1;
         let column_end = end_pos.column + 1;
 
+        // Downgrade the finding one severity level when the tainted value was sanitized
+        // (escaped, hashed, encrypted, ...) before reaching the sink; it is still reported.
+        let sanitized_by = ctx.find_sanitizer(&ctx.get_code_block(node), data_elements);
+        let mut severity = data_elements
+            .iter()
+            .map(|elem| &elem.sensitivity)
+            .min()
+            .map(|s| match s {
+                Sensitivity::Critical => Severity::Critical,
+                Sensitivity::Medium => Severity::Medium,
+                Sensitivity::Low => Severity::Low,
+            })
+            .unwrap();
+        if sanitized_by.is_some() {
+            severity = severity.downgraded();
+        }
+
+        let data_element_ids: Vec<String> =
+            data_elements.iter().map(|elem| elem.id.clone()).collect();
+
         Vulnerability {
             data_sink_id: data_sink.id.clone(),
-            data_element_ids: data_elements.iter().map(|elem| elem.id.clone()).collect(),
             data_element_names: data_elements.iter().map(|elem| elem.name.clone()).collect(),
-            hash: calculate_md5_hash(format!(
-                "{}|{}|{}|{}|{}",
-                ctx.config.repository.name,
-                ctx.config.repository.branch,
-                data_sink.id.clone(),
-                ctx.relative_file_path.display().to_string(),
-                ctx.get_node_text(node).trim(),
-            )),
+            hash: calculate_vulnerability_fingerprint(
+                &data_sink.id,
+                &data_element_ids,
+                &ctx.relative_file_path.display().to_string(),
+                &ctx.get_node_text(node),
+            ),
+            data_element_ids,
             description: data_sink.description.clone(),
-            severity: data_elements
-                .iter()
-                .map(|elem| &elem.sensitivity)
-                .min()
-                .map(|s| match s {
-                    Sensitivity::Critical => Severity::Critical,
-                    Sensitivity::Medium => Severity::Medium,
-                    Sensitivity::Low => Severity::Low,
-                })
-                .unwrap(),
+            severity,
+            sanitized_by,
+            code_frame: Some(ctx.code_frame(node, 2)),
             language: ctx.language.clone(),
             code_segment: ctx.get_code_block(node),
             absolute_file_path: ctx.absolute_file_path.display().to_string(),
@@ -331,6 +383,47 @@ impl Vulnerability {
     }
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GraphQLFinding {
+    pub data_element_ids: Vec<String>,
+    pub type_name: String,
+    pub field_name: String,
+    pub categories: Vec<String>,
+    pub hash: String,
+    pub language: Language,
+    pub code_segment: String,
+    pub relative_file_path: String,
+    pub absolute_file_path: String,
+    pub line: usize,
+    pub column: usize,
+    #[serde(default)]
+    pub deprecated: bool,
+}
+
+/// GraphQL-specific scan settings, letting teams adopt their own directive convention.
+#[derive(Clone, Debug)]
+pub struct GraphQLConfig {
+    /// Directive name carrying explicit annotations (e.g. `sensitive` for `@sensitive`).
+    pub directive: String,
+    /// Argument naming the sensitivity category (e.g. `category`).
+    pub category_arg: String,
+    /// Argument naming the sensitivity level (e.g. `level`).
+    pub level_arg: String,
+    /// Argument that suppresses a name-heuristic match (e.g. `ignore`).
+    pub ignore_arg: String,
+}
+
+impl Default for GraphQLConfig {
+    fn default() -> Self {
+        GraphQLConfig {
+            directive: "sensitive".to_string(),
+            category_arg: "category".to_string(),
+            level_arg: "level".to_string(),
+            ignore_arg: "ignore".to_string(),
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct DataflowVisualization {
     pub data_element_id: String,
@@ -351,6 +444,30 @@ pub struct ScanConfig {
     pub skip_data_sinks: HashSet<String>,
     pub skip_occurrences: HashSet<String>,
     pub skip_vulnerabilities: HashSet<String>,
+    /// Only report vulnerabilities/occurrences at one of these severities; empty means no
+    /// filtering.
+    pub include_severity: Vec<Severity>,
+    /// Surfacing a vulnerability at or above this severity makes the scan exit non-zero.
+    pub fail_severity_threshold: Option<Severity>,
+    pub graphql: GraphQLConfig,
+    /// Declared monorepo targets. Empty means the repo is treated as a single target.
+    pub targets: Vec<Target>,
+    /// Baseline revision for diff-scoped scanning; `None` scans the whole working tree.
+    pub diff_baseline: Option<String>,
+    pub empty_diff_mode: EmptyDiffMode,
+    pub unmatched_path_mode: UnmatchedPathMode,
+    /// Emit `syntect`-highlighted HTML spans for code blocks in the Markdown report, instead of
+    /// a plain fenced code block, so GitHub-rendered reports show colored code.
+    pub markdown_syntax_highlighting: bool,
+    /// Aho-Corasick automaton over every data element's normalized name, built once for the
+    /// whole scan and shared by every file's [`FileScanContext`] so identifier/property lookups
+    /// don't re-walk `data_elements` for each AST node.
+    pub data_element_matcher: DataElementMatcher,
+    /// Compiled `--include` globs; when set, only files matching one of them are scanned.
+    pub include_globs: Option<GlobSet>,
+    /// Compiled `--exclude` globs; files matching one of them are skipped, taking precedence
+    /// over `include_globs`.
+    pub exclude_globs: Option<GlobSet>,
 }
 
 #[derive(Debug, Serialize)]
@@ -364,8 +481,16 @@ pub struct ScanResults<'a> {
     pub data_elements: &'a HashMap<String, DataElement>,
     #[serde(skip)]
     pub data_sinks: &'a HashMap<Language, HashMap<String, DataSink>>,
+    #[serde(skip)]
+    pub markdown_syntax_highlighting: bool,
     pub vulnerabilities: Vec<Vulnerability>,
     pub occurrences: Vec<DataElementOccurrence>,
+    /// Whether `vulnerabilities`, as passed into [`ScanResults::new`], contained one meeting or
+    /// exceeding `config.fail_severity_threshold`. Computed before `ScanResults::new` applies its
+    /// `--include-severity` filtering, so a narrower `--include-severity` can never hide a
+    /// vulnerability the CI fail gate was asked to catch.
+    #[serde(skip)]
+    pub exceeds_fail_severity_threshold: bool,
 }
 
 impl<'a> ScanResults<'a> {
@@ -374,6 +499,30 @@ impl<'a> ScanResults<'a> {
         mut vulnerabilities: Vec<Vulnerability>,
         mut occurrences: Vec<DataElementOccurrence>,
     ) -> ScanResults<'a> {
+        let exceeds_fail_severity_threshold = match &config.fail_severity_threshold {
+            Some(threshold) => {
+                vulnerabilities.iter().any(|v| v.severity.meets_or_exceeds(threshold))
+            }
+            None => false,
+        };
+
+        // Restrict to the requested severities, e.g. `--include-severity critical` to silence
+        // lower-severity noise in a CI gate. Applied after `exceeds_fail_severity_threshold` is
+        // computed above, so `--include-severity` can narrow what's reported without also
+        // narrowing what `--fail-severity-threshold` gates on.
+        if !config.include_severity.is_empty() {
+            vulnerabilities
+                .retain(|vulnerability| config.include_severity.contains(&vulnerability.severity));
+            occurrences.retain(|occurrence| {
+                let severity = match occurrence.sensitivity {
+                    Sensitivity::Critical => Severity::Critical,
+                    Sensitivity::Medium => Severity::Medium,
+                    Sensitivity::Low => Severity::Low,
+                };
+                config.include_severity.contains(&severity)
+            });
+        }
+
         vulnerabilities.sort_by(|a, b| a.severity.cmp(&b.severity));
         occurrences.sort_by(|a, b| a.sensitivity.cmp(&b.sensitivity));
 
@@ -383,8 +532,10 @@ impl<'a> ScanResults<'a> {
             output_format: &config.output_format,
             data_elements: &config.data_elements,
             data_sinks: &config.data_sinks,
+            markdown_syntax_highlighting: config.markdown_syntax_highlighting,
             vulnerabilities,
             occurrences,
+            exceeds_fail_severity_threshold,
         }
     }
 
@@ -442,6 +593,12 @@ impl<'a> ScanResults<'a> {
             .collect()
     }
 
+    /// Findings whose tainted value was sanitized before the sink. Reports can list these
+    /// separately from live vulnerabilities so reviewers can focus on the unsanitized flows.
+    pub fn get_sanitized_vulnerabilities(&self) -> Vec<&Vulnerability> {
+        self.vulnerabilities.iter().filter(|v| v.sanitized_by.is_some()).collect()
+    }
+
     pub fn get_vulnerability_counts(&self) -> VulnerabilityCounts {
         VulnerabilityCounts {
             critical: self
@@ -543,11 +700,18 @@ pub struct CodeScope {
     pub scope_name: String,
     // Variable aliases (from imports, assignments etc).
     pub aliases: HashMap<String, String>,
+    // Taint symbol table: local variable name -> set of data-element ids it carries.
+    pub symbols: HashMap<String, HashSet<String>>,
 }
 
 impl<'a> CodeScope {
     pub fn new(scope_type: ScopeType, scope_name: String) -> CodeScope {
-        CodeScope { scope_type, scope_name, aliases: HashMap::new() }
+        CodeScope {
+            scope_type,
+            scope_name,
+            aliases: HashMap::new(),
+            symbols: HashMap::new(),
+        }
     }
 }
 
@@ -559,11 +723,43 @@ pub struct FileScanContext<'a> {
     pub display_file_path: String,
     pub source: &'a [u8],
     pub language: Language,
+    pub line_index: LineIndex,
+    pub suppressions: SuppressionIndex,
     scopes: Vec<CodeScope>,
     data_sinks_cache: HashMap<String, &'a DataSink>,
 
     data_elements_cache: HashMap<String, &'a DataElement>,
     pub data_element_aliases: HashMap<String, Vec<String>>,
+
+    /// Fallback for when the shared `DataElementMatcher` misses (e.g. the identifier doesn't
+    /// literally contain the element's name but still matches a broader include pattern): a
+    /// single-pass candidate check across every data element's include patterns, built once per
+    /// file. `None` if the combined pattern set failed to compile (e.g. it exceeded `RegexSet`'s
+    /// internal size limits) — `find_data_element` falls back further to the element-by-element
+    /// scan in that case.
+    element_regex_set: Option<RegexSet>,
+    /// Parallel to `element_regex_set`: `element_regex_owners[i]` is the data element whose
+    /// include pattern matched `RegexSet` index `i`.
+    element_regex_owners: Vec<&'a DataElement>,
+}
+
+/// Build a `RegexSet` covering every include pattern of every data element, plus the parallel
+/// vec mapping each set index back to its owning element. Capture-group refinement and the
+/// exclude-pattern/validator checks still go through `DataElement::is_match` on the individual
+/// compiled `Regex` objects — the set is only used to narrow the candidates worth checking.
+fn build_element_regex_set(
+    data_elements: &HashMap<String, DataElement>,
+) -> (Option<RegexSet>, Vec<&DataElement>) {
+    let mut owners = Vec::new();
+    let mut patterns = Vec::new();
+    for data_element in data_elements.values() {
+        for pattern in &data_element.include_patterns {
+            owners.push(data_element);
+            patterns.push(pattern.as_str());
+        }
+    }
+    let regex_set = RegexSet::new(&patterns).ok();
+    (regex_set, owners)
 }
 
 impl<'a> FileScanContext<'a> {
@@ -574,6 +770,8 @@ impl<'a> FileScanContext<'a> {
         file_source: &'a [u8],
     ) -> FileScanContext<'a> {
         let relative_file_path = file_path.strip_prefix(&scan_config.repository.path).unwrap();
+        let (element_regex_set, element_regex_owners) =
+            build_element_regex_set(&scan_config.data_elements);
 
         FileScanContext {
             database: scan_database,
@@ -583,10 +781,14 @@ impl<'a> FileScanContext<'a> {
             display_file_path: relative_file_path.display().to_string(),
             source: file_source,
             language: get_file_language(&file_path).unwrap(),
+            line_index: LineIndex::new(file_source),
+            suppressions: SuppressionIndex::new(file_source),
             scopes: vec![],
             data_sinks_cache: HashMap::new(),
             data_elements_cache: HashMap::new(),
             data_element_aliases: HashMap::new(),
+            element_regex_set,
+            element_regex_owners,
         }
     }
 
@@ -635,11 +837,31 @@ impl<'a> FileScanContext<'a> {
         }
 
         let normalized_name = name.replace(".", "_");
+
+        // Fast path: the shared Aho-Corasick automaton (built once for the whole scan from
+        // every data element's normalized name) resolves the common case — the identifier
+        // literally contains an element's name — in a single pass with no per-element probing.
         let data_element = self
             .config
-            .data_elements
-            .values()
-            .find(|data_element| data_element.is_match(&normalized_name));
+            .data_element_matcher
+            .find_matches(&normalized_name)
+            .into_iter()
+            .filter_map(|id| self.config.data_elements.get(id))
+            .find(|data_element| data_element.is_match(&normalized_name))
+            .or_else(|| match &self.element_regex_set {
+                // Narrow to the elements whose include pattern matched in a single pass, then
+                // run the usual exclude-pattern/validator checks only on those few candidates.
+                Some(regex_set) => regex_set
+                    .matches(&normalized_name)
+                    .into_iter()
+                    .map(|index| self.element_regex_owners[index])
+                    .find(|data_element| data_element.is_match(&normalized_name)),
+                None => self
+                    .config
+                    .data_elements
+                    .values()
+                    .find(|data_element| data_element.is_match(&normalized_name)),
+            });
 
         match data_element {
             Some(data_element) => {
@@ -650,6 +872,20 @@ impl<'a> FileScanContext<'a> {
         }
     }
 
+    /// Look for a sanitizer applied to `code` matching the source of any of the tainted
+    /// `data_elements`. Returns the matching sanitizer's type, used to downgrade findings on
+    /// sensitive values that are escaped/encrypted/hashed before a sink.
+    pub fn find_sanitizer(&self, code: &str, data_elements: &[&DataElement]) -> Option<String> {
+        let sources: HashSet<&Source> = data_elements.iter().map(|elem| &elem.source).collect();
+        self.config
+            .sanitizers
+            .iter()
+            .find(|sanitizer| {
+                sources.contains(&sanitizer.source) && sanitizer.pattern.is_match(code)
+            })
+            .map(|sanitizer| sanitizer.sanitizer_type.clone())
+    }
+
     pub fn find_data_sink(&mut self, name: &str) -> Option<&'a DataSink> {
         if let Some(data_sink) = self.data_sinks_cache.get(name) {
             return Some(data_sink);
@@ -714,20 +950,10 @@ impl<'a> FileScanContext<'a> {
     }
 
     pub fn get_code_line(&self, node: &Node) -> String {
-        let mut start = node.start_byte();
-        let mut end = node.end_byte();
-
-        // Find the start of the line
-        start = self.source[..start]
-            .iter()
-            .rposition(|&ch| ch == b'\n')
-            .map_or(0, |position| position + 1);
-
-        // Find the end of the line
-        end = self.source[end..]
-            .iter()
-            .position(|&ch| ch == b'\n')
-            .map_or(self.source.len(), |pos| end + pos);
+        // Slice the line(s) spanning the node using the precomputed newline index rather than
+        // rescanning the source buffer for line boundaries on every finding.
+        let start = self.line_index.line_range(node.start_byte()).start;
+        let end = self.line_index.line_range(node.end_byte()).end;
 
         // Trim whitespaces, commas, and semicolons from the beginning and end of the line
         String::from_utf8_lossy(&self.source[start..end])
@@ -735,23 +961,126 @@ impl<'a> FileScanContext<'a> {
             .to_string()
     }
 
+    /// Build a [`CodeFrame`] of `context` lines before and after the node's match line, using
+    /// the newline-offset index to slice rather than rescanning the source.
+    pub fn code_frame(&self, node: &Node, context: usize) -> CodeFrame {
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+
+        let from = start_line.saturating_sub(context).max(1);
+        let to = (end_line + context).min(self.line_index.line_count());
+
+        let mut frame_lines = Vec::new();
+        for line_no in from..=to {
+            let Some(range) = self.line_index.range_of_line(line_no) else { break };
+            frame_lines.push(CodeFrameLine {
+                line: line_no,
+                text: String::from_utf8_lossy(&self.source[range]).to_string(),
+                is_match: line_no >= start_line && line_no <= end_line,
+            });
+        }
+
+        CodeFrame {
+            lines: frame_lines,
+            column_start: node.start_position().column + 1,
+            column_end: node.end_position().column + 1,
+        }
+    }
+
+    /// The enclosing class/function path as a dotted qualified name, for taint keys.
+    pub fn qualified_scope_name(&self) -> String {
+        self.scopes
+            .iter()
+            .filter(|s| matches!(s.scope_type, ScopeType::Class | ScopeType::Function))
+            .map(|s| s.scope_name.as_str())
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    fn function_id(&self) -> FunctionId {
+        FunctionId {
+            relative_file_path: self.relative_file_path.display().to_string(),
+            qualified_name: self.qualified_scope_name(),
+        }
+    }
+
+    /// Record an import alias (`alias` -> fully-qualified `target`) for cross-file resolution.
+    pub fn record_taint_import(&self, alias: String, target: String) {
+        self.database
+            .taint()
+            .record_import(&self.relative_file_path.display().to_string(), alias, target);
+    }
+
+    /// Record that `callee` matched a data sink so flows reaching it become vulnerabilities.
+    pub fn record_taint_sink(&self, callee: String, data_sink_id: String) {
+        self.database.taint().record_sink(callee, data_sink_id);
+    }
+
+    /// Register the current function's parameters for taint propagation.
+    pub fn record_taint_function(&self, params: Vec<String>) {
+        self.database
+            .taint()
+            .record_function(self.function_id(), FunctionSummary { params, ..Default::default() });
+    }
+
+    /// Append a call site observed inside the current function.
+    pub fn record_taint_call(&self, call: CallSite) {
+        self.database.taint().push_call(self.function_id(), call);
+    }
+
     pub fn put_alias(&mut self, name: String, alias: String) {
         if let Some(scope) = self.scopes.last_mut() {
             scope.aliases.insert(name, alias);
         }
     }
 
+    /// Record `name` as carrying `element_ids` in the current scope, replacing whatever it
+    /// carried before (a plain reassignment severs the old taint).
+    pub fn taint_variable(&mut self, name: String, element_ids: HashSet<String>) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.symbols.insert(name, element_ids);
+        }
+    }
+
+    /// Record `name` as carrying the union of its existing taint (if any, visible anywhere in
+    /// the scope chain) and `element_ids`, for augmented assignments (`+=`) that extend a value
+    /// rather than replace it.
+    pub fn union_taint_variable(&mut self, name: &str, element_ids: HashSet<String>) {
+        let mut combined = self.lookup_tainted_variable(name).unwrap_or_default();
+        combined.extend(element_ids);
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.symbols.insert(name.to_string(), combined);
+        }
+    }
+
+    /// Look up `name`'s taint, searching from the innermost scope outward so a variable shadowed
+    /// in an inner scope doesn't pick up an outer scope's taint for the same name.
+    pub fn lookup_tainted_variable(&self, name: &str) -> Option<HashSet<String>> {
+        self.scopes.iter().rev().find_map(|scope| scope.symbols.get(name).cloned())
+    }
+
     pub fn put_occurrence(&self, occurrence: DataElementOccurrence) -> Result<()> {
-        if !self.config.skip_occurrences.contains(&occurrence.hash) {
-            self.database.put_data_element_occurrence(&occurrence).unwrap();
+        if self.config.skip_occurrences.contains(&occurrence.hash)
+            || self.suppressions.is_suppressed(occurrence.line_start, &occurrence.data_element_id)
+        {
+            return Ok(());
         }
+        self.database.put_data_element_occurrence(&occurrence).unwrap();
         Ok(())
     }
 
     pub fn put_vulnerability(&self, vulnerability: Vulnerability) -> Result<()> {
-        if !self.config.skip_vulnerabilities.contains(&vulnerability.hash) {
-            self.database.put_vulnerability(&vulnerability).unwrap()
+        // A vulnerability is suppressed by an unscoped directive, or one naming its sink or
+        // any of the data elements that reached it.
+        let suppressed = self.suppressions.is_suppressed(vulnerability.line_start, &vulnerability.data_sink_id)
+            || vulnerability
+                .data_element_ids
+                .iter()
+                .any(|id| self.suppressions.is_suppressed(vulnerability.line_start, id));
+        if self.config.skip_vulnerabilities.contains(&vulnerability.hash) || suppressed {
+            return Ok(());
         }
+        self.database.put_vulnerability(&vulnerability).unwrap();
         Ok(())
     }
 }
@@ -4,6 +4,7 @@ use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
 
 use anyhow::Result;
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use ignore::WalkBuilder;
 use strum::IntoEnumIterator;
 
@@ -18,7 +19,7 @@ pub fn get_repository_info(path: &PathBuf, ci_type: &Option<CiType>) -> Result<R
         .collect::<HashMap<Language, FileStats>>();
     let mut total_file_stats = FileStats::default();
 
-    for file in get_files_in_dir(path) {
+    for file in get_files_in_dir(path, None, None) {
         if let Some(language) = get_file_language(&file) {
             if let Ok(lines) = get_file_line_count(&file) {
                 per_lang_file_stats.entry(language).and_modify(|s| {
@@ -74,9 +75,55 @@ pub fn get_repository_info(path: &PathBuf, ci_type: &Option<CiType>) -> Result<R
     }
 }
 
-pub fn get_files_in_dir(dir_path: &PathBuf) -> impl Iterator<Item = PathBuf> {
+/// Compile repeatable `--include`/`--exclude` glob strings into a `GlobSet` anchored at the
+/// scan root, for [`get_files_in_dir`]'s pathspec-style scan scoping. `None` for an empty
+/// pattern list so callers can treat "no filter" uniformly.
+pub fn build_glob_set(patterns: &[String]) -> Result<Option<GlobSet>> {
+    if patterns.is_empty() {
+        return Ok(None);
+    }
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(Glob::new(pattern)?);
+    }
+    Ok(Some(builder.build()?))
+}
+
+/// Walk `dir_path`, honoring `.hounddogignore` as usual, and additionally restrict results with
+/// pathspec-style globs: `exclude` prunes matching files (and whole directories, so the walk
+/// doesn't descend into them) while `include`, when given, requires a file to match before it's
+/// yielded. Directories are never tested against `include` since a directory that doesn't itself
+/// match (e.g. `src/**/*.ts`) may still contain files that do.
+pub fn get_files_in_dir(
+    dir_path: &PathBuf,
+    include: Option<&GlobSet>,
+    exclude: Option<&GlobSet>,
+) -> impl Iterator<Item = PathBuf> {
+    let root = dir_path.clone();
+    let include = include.cloned();
+    let exclude = exclude.cloned();
+
     WalkBuilder::new(dir_path)
         .add_custom_ignore_filename(".hounddogignore")
+        .filter_entry(move |entry| {
+            let relative_path = match entry.path().strip_prefix(&root) {
+                Ok(relative_path) if !relative_path.as_os_str().is_empty() => relative_path,
+                _ => return true, // The root itself.
+            };
+
+            if let Some(exclude) = &exclude {
+                if exclude.is_match(relative_path) {
+                    return false;
+                }
+            }
+            if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+                return true;
+            }
+            match &include {
+                Some(include) => include.is_match(relative_path),
+                None => true,
+            }
+        })
         .build()
         .filter_map(Result::ok)
         .filter(|entry| entry.file_type().unwrap().is_file())
@@ -97,7 +144,7 @@ pub fn get_file_language(file_path: &Path) -> Option<Language> {
     }
 }
 
-fn get_file_line_count(file_path: &PathBuf) -> Result<usize> {
+pub(crate) fn get_file_line_count(file_path: &PathBuf) -> Result<usize> {
     let file = File::open(file_path)?;
     let mut reader = BufReader::with_capacity(1024 * 32, file);
     let mut count = 0;
@@ -1,4 +1,6 @@
+use std::collections::HashMap;
 use std::env;
+use std::ops::RangeInclusive;
 use std::path::PathBuf;
 
 use anyhow::Result;
@@ -116,6 +118,9 @@ pub fn get_git_diff_files(repo: &Repository, baseline: Option<&str>) -> Result<V
             let diff_tree = repo.diff_tree_to_tree(Some(&baseline_tree), Some(&head_tree), None)?;
             diff_tree
                 .deltas()
+                // A deletion's `new_file()` still carries the old path for display purposes;
+                // there's nothing left on disk to scan, so skip it rather than report it changed.
+                .filter(|delta| delta.status() != git2::Delta::Deleted)
                 .map(|delta| delta.new_file().path())
                 .filter_map(|path| path)
                 .map(|path| path.to_path_buf())
@@ -125,6 +130,68 @@ pub fn get_git_diff_files(repo: &Repository, baseline: Option<&str>) -> Result<V
     })
 }
 
+/// Map each file touched by the diff against `baseline` to the line ranges it changed, so callers
+/// can report only findings that fall on a changed line instead of the whole file. Renamed files
+/// are keyed by their new path (matching [`get_git_diff_files`]'s rename handling) so line ranges
+/// resolve correctly; an added file maps to a single range spanning the whole file, since there's
+/// no old version to diff hunks against.
+pub fn get_git_diff_line_ranges(
+    repo: &Repository,
+    baseline: &str,
+) -> Result<HashMap<PathBuf, Vec<RangeInclusive<usize>>>> {
+    let baseline_tree = repo
+        .revparse_single(baseline)
+        .map_err(|e| {
+            if e.code() == git2::ErrorCode::NotFound {
+                err!("Git diff baseline '{}' not found", baseline)
+            } else {
+                sentry_err!("Cannot get Git diff baseline '{}': {}", baseline, e)
+            }
+        })?
+        .peel_to_tree()?;
+    let head_tree = repo.head()?.peel_to_tree()?;
+    let diff = repo.diff_tree_to_tree(Some(&baseline_tree), Some(&head_tree), None)?;
+
+    let mut ranges: HashMap<PathBuf, Vec<RangeInclusive<usize>>> = HashMap::new();
+    diff.foreach(
+        &mut |delta, _| {
+            if delta.status() == git2::Delta::Added {
+                if let Some(path) = delta.new_file().path() {
+                    ranges.entry(path.to_path_buf()).or_default().push(1..=usize::MAX);
+                }
+            }
+            true
+        },
+        None,
+        None,
+        Some(&mut |delta, _hunk, line| {
+            if line.origin() == '+' {
+                if let (Some(path), Some(new_lineno)) =
+                    (delta.new_file().path(), line.new_lineno())
+                {
+                    let line_no = new_lineno as usize;
+                    ranges.entry(path.to_path_buf()).or_default().push(line_no..=line_no);
+                }
+            }
+            true
+        }),
+    )?;
+    Ok(ranges)
+}
+
+/// Whether `line_start..=line_end` in `relative_file_path` falls on a line the diff changed,
+/// per [`get_git_diff_line_ranges`].
+pub fn intersects_changed_lines(
+    changed_ranges: &HashMap<PathBuf, Vec<RangeInclusive<usize>>>,
+    relative_file_path: &str,
+    line_start: usize,
+    line_end: usize,
+) -> bool {
+    changed_ranges.get(&PathBuf::from(relative_file_path)).is_some_and(|ranges| {
+        ranges.iter().any(|range| range.start() <= &line_end && &line_start <= range.end())
+    })
+}
+
 pub fn get_url_link(
     git_provider: &Option<GitProvider>,
     remote_url: &str,
@@ -1,4 +1,5 @@
 use md5::{Digest, Md5};
+use sha2::Sha256;
 
 pub fn calculate_md5_hash(data: String) -> String {
     // Create a new MD5 hasher instance
@@ -18,3 +19,92 @@ pub fn calculate_md5_hash(data: String) -> String {
 
     hash_str.to_uppercase()
 }
+
+/// Multicodec code for `sha2-256`, and the digest's fixed byte length, per
+/// <https://github.com/multiformats/multicodec>.
+const SHA2_256_CODE: u8 = 0x12;
+const SHA2_256_DIGEST_LEN: u8 = 0x20;
+
+/// The Bitcoin/IPFS base58 alphabet used by multibase's `base58btc` encoding (`z` prefix).
+const BASE58BTC_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// A self-describing, content-addressed fingerprint for a data element occurrence: a sha2-256
+/// digest over `(relative_file_path, line_number, code_segment)`, wrapped as a multihash
+/// (multicodec + length prefix, then the digest) and encoded with multibase's `base58btc`, so
+/// downstream tooling can tell which hash algorithm produced it without out-of-band knowledge.
+pub fn calculate_content_fingerprint(
+    relative_file_path: &str,
+    line_number: usize,
+    code_segment: &str,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(relative_file_path.as_bytes());
+    hasher.update(b"|");
+    hasher.update(line_number.to_string().as_bytes());
+    hasher.update(b"|");
+    hasher.update(code_segment.trim().as_bytes());
+    multihash_fingerprint(hasher)
+}
+
+/// A stable, content-addressed fingerprint for a `Vulnerability`, built from the rule it matched,
+/// the sorted set of tainted data elements, the file it was found in, and the matched snippet —
+/// deliberately excluding line numbers, so inserting or removing lines elsewhere in the file (or
+/// above the finding) doesn't change the finding's identity between scans. Same multihash/
+/// `base58btc` encoding as [`calculate_content_fingerprint`].
+pub fn calculate_vulnerability_fingerprint(
+    data_sink_id: &str,
+    data_element_ids: &[String],
+    relative_file_path: &str,
+    code_segment: &str,
+) -> String {
+    let mut sorted_data_element_ids = data_element_ids.to_vec();
+    sorted_data_element_ids.sort();
+
+    let mut hasher = Sha256::new();
+    hasher.update(data_sink_id.as_bytes());
+    hasher.update(b"|");
+    hasher.update(sorted_data_element_ids.join(",").as_bytes());
+    hasher.update(b"|");
+    hasher.update(relative_file_path.as_bytes());
+    hasher.update(b"|");
+    hasher.update(code_segment.trim().as_bytes());
+    multihash_fingerprint(hasher)
+}
+
+/// Finish a sha2-256 digest and wrap it as a multihash (multicodec + length prefix, then the
+/// digest), encoded with multibase's `base58btc`.
+fn multihash_fingerprint(hasher: Sha256) -> String {
+    let digest = hasher.finalize();
+
+    let mut multihash = Vec::with_capacity(2 + digest.len());
+    multihash.push(SHA2_256_CODE);
+    multihash.push(SHA2_256_DIGEST_LEN);
+    multihash.extend_from_slice(&digest);
+
+    format!("z{}", encode_base58btc(&multihash))
+}
+
+/// Encode `bytes` as multibase's `base58btc`, without the leading `z` multibase prefix (callers
+/// that want the full self-describing string add it themselves).
+fn encode_base58btc(bytes: &[u8]) -> String {
+    let leading_zeros = bytes.iter().take_while(|&&b| b == 0).count();
+
+    let mut digits: Vec<u8> = Vec::new();
+    for &byte in bytes {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) * 256;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut encoded = String::with_capacity(leading_zeros + digits.len());
+    encoded.extend(std::iter::repeat('1').take(leading_zeros));
+    encoded.extend(digits.iter().rev().map(|&digit| BASE58BTC_ALPHABET[digit as usize] as char));
+    encoded
+}
@@ -0,0 +1,7 @@
+pub mod file;
+pub mod git;
+pub mod hash;
+pub mod pkce;
+pub mod serde;
+pub mod table;
+pub mod validator;
@@ -1,10 +1,24 @@
 use std::fmt;
+use std::path::Path;
 
 use anyhow::Result;
 use regex::Regex;
+use serde::de::DeserializeOwned;
 use serde::{de, Serialize, Serializer};
 use serde::Deserializer;
 
+/// Deserialize `T` from a config/rule file, dispatching on its extension: `.yaml`/`.yml` (YAML),
+/// `.toml` (TOML), `.ron` (RON), and everything else (including `.json`) as JSON.
+pub fn deserialize_from_path<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let content = std::fs::read_to_string(path)?;
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("yaml") | Some("yml") => Ok(serde_yaml::from_str(&content)?),
+        Some("toml") => Ok(toml::from_str(&content)?),
+        Some("ron") => Ok(ron::from_str(&content)?),
+        _ => Ok(serde_json::from_str(&content)?),
+    }
+}
+
 pub fn serialize_regex<S>(regex: &Regex, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -0,0 +1,169 @@
+use serde::Deserialize;
+
+fn default_min_entropy() -> f64 {
+    3.5
+}
+
+fn default_min_length() -> usize {
+    16
+}
+
+/// Structural check run on the substring matched by a `DataElement`'s include pattern,
+/// rejecting regex hits that are syntactically plausible but cannot be the real thing.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Validator {
+    /// Luhn checksum, for payment card primary account numbers.
+    Luhn,
+    /// ISO 13616 IBAN mod-97 checksum.
+    Iban,
+    /// Checksum check for crypto wallet addresses (bech32 polymod, else base58 shape).
+    WalletAddress,
+    /// Shannon-entropy gate for high-entropy secrets and API tokens.
+    Entropy {
+        #[serde(default = "default_min_entropy")]
+        min_entropy: f64,
+        #[serde(default = "default_min_length")]
+        min_length: usize,
+    },
+}
+
+impl Validator {
+    /// Returns true if `s` passes the structural check for this validator.
+    pub fn is_valid(&self, s: &str) -> bool {
+        match self {
+            Validator::Luhn => luhn_valid(s),
+            Validator::Iban => iban_valid(s),
+            Validator::WalletAddress => wallet_address_valid(s),
+            Validator::Entropy { min_entropy, min_length } => {
+                s.len() >= *min_length && shannon_entropy(s) >= *min_entropy
+            }
+        }
+    }
+}
+
+/// Luhn (mod-10) checksum over the digits of `s`, ignoring separators.
+fn luhn_valid(s: &str) -> bool {
+    let digits: Vec<u32> = s.chars().filter_map(|c| c.to_digit(10)).collect();
+    if digits.len() < 12 {
+        return false;
+    }
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| {
+            if i % 2 == 1 {
+                let doubled = d * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                d
+            }
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+/// IBAN mod-97 check: move the country code and check digits to the end, map letters to
+/// their two-digit values, and verify the resulting integer is congruent to 1 modulo 97.
+fn iban_valid(s: &str) -> bool {
+    let iban: String = s.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_uppercase();
+    if iban.len() < 15 || iban.len() > 34 || !iban.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return false;
+    }
+    let (head, tail) = iban.split_at(4);
+    let rearranged = format!("{}{}", tail, head);
+
+    let mut remainder: u32 = 0;
+    for ch in rearranged.chars() {
+        let value = if ch.is_ascii_digit() {
+            ch.to_digit(10).unwrap()
+        } else {
+            (ch as u32) - ('A' as u32) + 10
+        };
+        // Fold digit-by-digit so the accumulator never overflows.
+        for digit in value.to_string().chars() {
+            remainder = (remainder * 10 + digit.to_digit(10).unwrap()) % 97;
+        }
+    }
+    remainder == 1
+}
+
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BECH32_ALPHABET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Verify a wallet address: a bech32 checksum when the string is bech32-shaped, otherwise a
+/// base58 shape/length check (base58check's digest is not available without a SHA-2 hasher).
+fn wallet_address_valid(s: &str) -> bool {
+    if let Some(sep) = s.rfind('1') {
+        let lower = s.to_lowercase();
+        if lower.chars().all(|c| c.is_ascii()) && sep >= 1 && s.len() - sep - 1 >= 6 {
+            if let Some(valid) = bech32_checksum_valid(&lower, sep) {
+                return valid;
+            }
+        }
+    }
+    let len = s.chars().count();
+    (25..=62).contains(&len) && s.chars().all(|c| BASE58_ALPHABET.contains(c))
+}
+
+/// bech32 polymod checksum over `hrp` (everything before the last `1`) and the data part.
+fn bech32_checksum_valid(s: &str, sep: usize) -> Option<bool> {
+    let (hrp, data_part) = (&s[..sep], &s[sep + 1..]);
+    if hrp.is_empty() || data_part.len() < 6 {
+        return None;
+    }
+    let mut values: Vec<u8> = Vec::new();
+    for c in hrp.chars() {
+        values.push((c as u8) >> 5);
+    }
+    values.push(0);
+    for c in hrp.chars() {
+        values.push((c as u8) & 0x1f);
+    }
+    for c in data_part.chars() {
+        let idx = BECH32_ALPHABET.find(c)?;
+        values.push(idx as u8);
+    }
+    Some(bech32_polymod(&values) == 1)
+}
+
+fn bech32_polymod(values: &[u8]) -> u32 {
+    const GEN: [u32; 5] = [0x3b6a_57b2, 0x2650_8e6d, 0x1ea1_19fa, 0x3d42_33dd, 0x2a14_62b3];
+    let mut chk: u32 = 1;
+    for &value in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x1ff_ffff) << 5) ^ (value as u32);
+        for (i, g) in GEN.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= g;
+            }
+        }
+    }
+    chk
+}
+
+/// Shannon entropy `H = -Σ p_i·log2(p_i)` in bits per character over the byte-frequency
+/// distribution of `s`, the standard way to separate random secrets from dictionary words.
+fn shannon_entropy(s: &str) -> f64 {
+    if s.is_empty() {
+        return 0.0;
+    }
+    let mut counts = [0usize; 256];
+    for &byte in s.as_bytes() {
+        counts[byte as usize] += 1;
+    }
+    let len = s.len() as f64;
+    counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
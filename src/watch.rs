@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
+
+use anyhow::Result;
+
+use crate::output::console::print_console_output;
+use crate::scanner;
+use crate::structs::ScanConfig;
+use crate::utils::file::get_files_in_dir;
+
+/// How often to re-check the scan root for changes. A `notify`-backed watcher would hook OS-level
+/// inotify/FSEvents events instead, but that's a dependency this crate doesn't already carry, so
+/// `--watch` polls file modification times at a fixed cadence — simple and portable, at the cost
+/// of this much fixed latency.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+/// How long a burst of changes must go quiet before triggering a rescan, so saving several files
+/// at once (e.g. a project-wide format) causes one rescan instead of several.
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(200);
+
+/// The modification time of every file the scanner would visit, honoring the same
+/// `.hounddogignore`/`--include`/`--exclude` rules as a real scan so build artifacts and output
+/// files don't trigger a rescan loop.
+fn snapshot(config: &ScanConfig) -> HashMap<PathBuf, SystemTime> {
+    get_files_in_dir(
+        &config.repository.path,
+        config.include_globs.as_ref(),
+        config.exclude_globs.as_ref(),
+    )
+        .filter_map(|file| {
+            let modified = std::fs::metadata(&file).ok()?.modified().ok()?;
+            Some((file, modified))
+        })
+        .collect()
+}
+
+/// Poll `config`'s scan root for filesystem changes, debounce bursts of them, and re-run the scan
+/// on each settled batch until the process is interrupted (e.g. Ctrl-C).
+pub fn watch(config: &ScanConfig) -> Result<()> {
+    let mut last_snapshot = snapshot(config);
+    let mut last_change: Option<Instant> = None;
+
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        let current_snapshot = snapshot(config);
+        if current_snapshot != last_snapshot {
+            last_snapshot = current_snapshot;
+            last_change = Some(Instant::now());
+            continue;
+        }
+
+        let Some(changed_at) = last_change else {
+            continue;
+        };
+        if changed_at.elapsed() < DEBOUNCE_WINDOW {
+            continue;
+        }
+        last_change = None;
+
+        print!("\x1B[2J\x1B[1;1H");
+        println!("Changes detected, rescanning ...");
+        let results = scanner::run_scan(config)?;
+        print_console_output(&results)?;
+    }
+}